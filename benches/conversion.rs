@@ -0,0 +1,88 @@
+//  ~/benches/conversion.rs
+//
+//  * Copyright (C) 2024–2025 Parsicore <parsicore.dev@gmail.com>
+//  * Package : mitra
+//  * License : Apache-2.0
+//  * Version : 2.3.0
+//  * URL     : https://github.com/parsicore/Mitra
+//  * Sign: mitra-20250419-bd5fbe728fa2-5836b45f25d83501625cc5529193d5f0
+//
+//! Batch-sized conversion workloads through `parsidate` and through the year-start
+//! table used by `mitra batch` with the `lookup-tables` feature. Run with `cargo bench`.
+
+#[path = "../src/year_table.rs"]
+mod year_table;
+
+use criterion::{Criterion, criterion_group, criterion_main};
+use parsidate::ParsiDate;
+use std::hint::black_box;
+
+/// Lines in one simulated `batch` run.
+const BATCH_SIZE: usize = 10_000;
+
+/// Parsi dates spread over 1300-1500, like a long-lived log.
+fn parsi_batch() -> Vec<ParsiDate> {
+    (0..BATCH_SIZE)
+        .map(|i| {
+            let i = i as u32;
+            ParsiDate::new(1300 + (i * 7 % 200) as i32, i % 12 + 1, i % 29 + 1).unwrap()
+        })
+        .collect()
+}
+
+fn bench_to_gregorian(c: &mut Criterion) {
+    let dates = parsi_batch();
+    for date in &dates {
+        assert_eq!(year_table::to_gregorian(date), date.to_gregorian().unwrap());
+    }
+
+    let mut group = c.benchmark_group("batch to-gregorian");
+    group.bench_function("parsidate", |b| {
+        b.iter(|| {
+            for date in &dates {
+                black_box(black_box(date).to_gregorian().unwrap());
+            }
+        })
+    });
+    group.bench_function("year table", |b| {
+        b.iter(|| {
+            for date in &dates {
+                black_box(year_table::to_gregorian(black_box(date)));
+            }
+        })
+    });
+    group.finish();
+}
+
+fn bench_from_gregorian(c: &mut Criterion) {
+    let dates: Vec<_> = parsi_batch()
+        .iter()
+        .map(|date| date.to_gregorian().unwrap())
+        .collect();
+    for date in &dates {
+        assert_eq!(
+            year_table::from_gregorian(*date),
+            Some(ParsiDate::from_gregorian(*date).unwrap())
+        );
+    }
+
+    let mut group = c.benchmark_group("batch from-gregorian");
+    group.bench_function("parsidate", |b| {
+        b.iter(|| {
+            for date in &dates {
+                black_box(ParsiDate::from_gregorian(black_box(*date)).unwrap());
+            }
+        })
+    });
+    group.bench_function("year table", |b| {
+        b.iter(|| {
+            for date in &dates {
+                black_box(year_table::from_gregorian(black_box(*date)));
+            }
+        })
+    });
+    group.finish();
+}
+
+criterion_group!(benches, bench_to_gregorian, bench_from_gregorian);
+criterion_main!(benches);
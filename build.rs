@@ -0,0 +1,70 @@
+//  ~/build.rs
+//
+//  * Copyright (C) 2024–2025 Parsicore <parsicore.dev@gmail.com>
+//  * Package : mitra
+//  * License : Apache-2.0
+//  * Version : 2.3.0
+//  * URL     : https://github.com/parsicore/Mitra
+//  * Sign: mitra-20250419-bd5fbe728fa2-5836b45f25d83501625cc5529193d5f0
+//
+//! Validates the embedded datasets (`src/data/events.json`, `src/data/onthisday.json`)
+//! when the `embedded-events` feature is enabled, so a malformed entry fails the build
+//! with its position instead of leaving the event set empty at runtime.
+//!
+//! The checks live in `src/dataset_schema.rs`, which `mitra doctor` runs as well.
+
+use std::fs;
+
+#[path = "src/dataset_schema.rs"]
+#[allow(dead_code)] // Entry counts are only reported by `mitra doctor`.
+mod dataset_schema;
+
+use dataset_schema::{EventsFile, OccasionsFile};
+
+const EVENTS_PATH: &str = "src/data/events.json";
+const OCCASIONS_PATH: &str = "src/data/onthisday.json";
+
+/// Parses one dataset and returns its problems, each prefixed with the file path.
+/// Schema errors (wrong types, unknown or missing keys) carry serde_json's line/column.
+fn validate<T: serde::de::DeserializeOwned>(
+    path: &str,
+    problems: impl Fn(&T) -> Vec<String>,
+) -> Vec<String> {
+    let json = match fs::read_to_string(path) {
+        Ok(json) => json,
+        Err(e) => return vec![format!("{}: cannot read: {}", path, e)],
+    };
+    match serde_json::from_str::<T>(&json) {
+        Ok(data) => problems(&data)
+            .into_iter()
+            .map(|problem| format!("{}: {}", path, problem))
+            .collect(),
+        Err(e) => vec![format!("{}: {}", path, e)],
+    }
+}
+
+fn main() {
+    println!("cargo:rerun-if-changed={}", EVENTS_PATH);
+    println!("cargo:rerun-if-changed={}", OCCASIONS_PATH);
+    println!("cargo:rerun-if-changed=build.rs");
+    println!("cargo:rerun-if-changed=src/dataset_schema.rs");
+
+    // The datasets are only compiled in with `embedded-events`.
+    if std::env::var_os("CARGO_FEATURE_EMBEDDED_EVENTS").is_none() {
+        return;
+    }
+
+    let problems: Vec<String> = validate(EVENTS_PATH, EventsFile::problems)
+        .into_iter()
+        .chain(validate(OCCASIONS_PATH, OccasionsFile::problems))
+        .collect();
+    if !problems.is_empty() {
+        for problem in &problems {
+            eprintln!("error: {}", problem);
+        }
+        panic!(
+            "embedded event data failed validation ({} problem(s))",
+            problems.len()
+        );
+    }
+}
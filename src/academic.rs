@@ -0,0 +1,176 @@
+//  ~/src/academic.rs
+//
+//  * Copyright (C) 2024–2025 Parsicore <parsicore.dev@gmail.com>
+//  * Package : mitra
+//  * License : Apache-2.0
+//  * Version : 2.3.0
+//  * URL     : https://github.com/parsicore/Mitra
+//  * Sign: mitra-20250419-bd5fbe728fa2-5836b45f25d83501625cc5529193d5f0
+//
+//! Academic-year arithmetic for the `academic` command. An academic year runs from its
+//! start day (1 مهر by default) to the day before the next one, and holds two terms:
+//! the first from the start day to the day before the second term begins (1 بهمن), the
+//! second up to the last day of classes (31 خرداد). The rest of the year is the summer
+//! break. Weeks start on Saturday; instructional days are business days (no Fridays or
+//! official holidays).
+
+use crate::business_days;
+use crate::utils::{map_mitra_error, signed_days_between, to_ascii_digits};
+use crate::weekday::{WeekExt, WeekStart};
+use anyhow::{Result, bail};
+use parsidate::ParsiDate;
+use serde::Serialize;
+
+/// A month and day that recurs every year, e.g. 07/01 for 1 مهر.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct MonthDay {
+    month: u32,
+    day: u32,
+}
+
+impl MonthDay {
+    /// Parses `MM/DD` (or `MM-DD`) or a day and Persian month name such as `1 مهر`.
+    pub fn parse(input: &str) -> Result<MonthDay> {
+        let trimmed = to_ascii_digits(input.trim());
+        let numeric = trimmed
+            .split_once(['/', '-'])
+            .and_then(|(m, d)| Some((m.parse().ok()?, d.parse().ok()?)));
+        let (month, day) = match numeric {
+            Some(parts) => parts,
+            None => {
+                // A leap year, so that 30 اسفند is accepted.
+                let date = ParsiDate::parse(&format!("{} 1403", trimmed), "%d %B %Y")
+                    .map_err(|_| {
+                        anyhow::anyhow!(
+                            "Error: Invalid day of year '{}'. Expected MM/DD (e.g., 07/01) or a day and month name (e.g., 1 مهر).",
+                            input
+                        )
+                    })?;
+                (date.month(), date.day())
+            }
+        };
+        if !(1..=12).contains(&month) || !(1..=31).contains(&day) || (month > 6 && day > 30) {
+            bail!("Error: Invalid day of year '{}'.", input);
+        }
+        Ok(MonthDay { month, day })
+    }
+
+    /// This month and day in `year`, clamped to the length of the month (for 30 اسفند).
+    fn in_year(self, year: i32) -> Result<ParsiDate> {
+        let day = self.day.min(ParsiDate::days_in_month(year, self.month));
+        ParsiDate::new(year, self.month, day)
+            .map_err(|e| map_mitra_error(e, "creating academic calendar date"))
+    }
+}
+
+/// Where the academic year and its terms begin and end.
+#[derive(Debug, Clone, Copy)]
+pub struct TermCalendar {
+    pub start: MonthDay,
+    pub second_term: MonthDay,
+    pub end: MonthDay,
+}
+
+impl TermCalendar {
+    /// Resolves a month and day to its date within the academic year that starts in
+    /// `start_year`: days before the start day belong to the following calendar year.
+    fn resolve(&self, start_year: i32, month_day: MonthDay) -> Result<ParsiDate> {
+        if month_day >= self.start {
+            month_day.in_year(start_year)
+        } else {
+            month_day.in_year(start_year + 1)
+        }
+    }
+}
+
+/// A date's position in the academic calendar.
+#[derive(Serialize, Debug)]
+pub struct AcademicInfo {
+    pub date: String,
+    pub weekday: String,
+    /// The academic year as "start-end", e.g. "1403-1404".
+    pub academic_year: String,
+    pub year_start: String,
+    pub year_end: String,
+    /// 1 or 2, or `None` during the summer break.
+    pub term: Option<u32>,
+    pub term_start: Option<String>,
+    pub term_end: Option<String>,
+    /// Week of the academic year, counting the (Saturday-based) week of the start day as 1.
+    pub academic_week: u32,
+    /// Week of the current term, counted the same way.
+    pub term_week: Option<u32>,
+    /// Business days from the date through the end of the term, inclusive.
+    pub instructional_days_left: u32,
+}
+
+/// Places `date` in the academic calendar described by `calendar`.
+pub fn academic_info(date: &ParsiDate, calendar: &TermCalendar) -> Result<AcademicInfo> {
+    let month_day = MonthDay {
+        month: date.month(),
+        day: date.day(),
+    };
+    let start_year = if month_day >= calendar.start {
+        date.year()
+    } else {
+        date.year() - 1
+    };
+    let year_start = calendar.resolve(start_year, calendar.start)?;
+    let year_end = calendar
+        .start
+        .in_year(start_year + 1)?
+        .add_days(-1)
+        .map_err(|e| map_mitra_error(e, "calculating end of academic year"))?;
+    let second_term = calendar.resolve(start_year, calendar.second_term)?;
+    let classes_end = calendar.resolve(start_year, calendar.end)?;
+    if second_term <= year_start || classes_end < second_term {
+        bail!(
+            "Error: Expected term start < second term <= classes end within one academic year, got {}, {}, {}.",
+            year_start,
+            second_term,
+            classes_end
+        );
+    }
+
+    let term = if *date < second_term {
+        let first_term_end = second_term
+            .add_days(-1)
+            .map_err(|e| map_mitra_error(e, "calculating end of first term"))?;
+        Some((1, year_start, first_term_end))
+    } else if *date <= classes_end {
+        Some((2, second_term, classes_end))
+    } else {
+        None
+    };
+
+    let instructional_days_left = match term {
+        Some((_, _, term_end)) => business_days::count_business_days(date, &term_end)?,
+        None => 0,
+    };
+    let term_week = match term {
+        Some((_, term_start, _)) => Some(week_number(&term_start, date)?),
+        None => None,
+    };
+
+    Ok(AcademicInfo {
+        date: date.to_string(),
+        weekday: date
+            .weekday()
+            .map_err(|e| map_mitra_error(e, "getting weekday"))?,
+        academic_year: format!("{}-{}", start_year, start_year + 1),
+        year_start: year_start.to_string(),
+        year_end: year_end.to_string(),
+        term: term.map(|(number, _, _)| number),
+        term_start: term.map(|(_, first, _)| first.to_string()),
+        term_end: term.map(|(_, _, last)| last.to_string()),
+        academic_week: week_number(&year_start, date)?,
+        term_week,
+        instructional_days_left,
+    })
+}
+
+/// The 1-based Saturday-to-Friday week of `date`, counting the week containing `first` as 1.
+fn week_number(first: &ParsiDate, date: &ParsiDate) -> Result<u32> {
+    let days = signed_days_between(&first.start_of_week(WeekStart::Sat)?, date)?;
+    Ok((days / 7) as u32 + 1)
+}
@@ -0,0 +1,122 @@
+//  ~/src/anniversary.rs
+//
+//  * Copyright (C) 2024–2025 Parsicore <parsicore.dev@gmail.com>
+//  * Package : mitra
+//  * License : Apache-2.0
+//  * Version : 2.3.0
+//  * URL     : https://github.com/parsicore/Mitra
+//  * Sign: mitra-20250419-bd5fbe728fa2-5836b45f25d83501625cc5529193d5f0
+//
+//! Yearly anniversaries of a historical date. A Parsi origin recurs on the same Parsi
+//! month and day; a Gregorian origin recurs on the same Gregorian month and day. An
+//! origin on 30 اسفند (or 29 February) is observed on the last day of the month in
+//! years that do not have that day.
+
+use crate::utils::{map_mitra_error, signed_days_between, supported_range};
+use anyhow::{Result, bail};
+use chrono::{Datelike, NaiveDate};
+use parsidate::ParsiDate;
+
+/// The date whose anniversaries are counted, in the calendar it recurs in.
+#[derive(Debug, Clone, Copy)]
+pub enum Origin {
+    Parsi(ParsiDate),
+    Gregorian(NaiveDate),
+}
+
+/// One anniversary.
+#[derive(Debug)]
+pub struct Anniversary {
+    /// Years since the origin.
+    pub years: i32,
+    pub date: ParsiDate,
+    /// Signed days from today (0 if the anniversary is today).
+    pub days_until: i64,
+    /// Set when the origin's day does not exist this year and an earlier day is used.
+    pub shifted: bool,
+}
+
+impl Origin {
+    /// The origin as a Parsi date.
+    pub fn parsi_date(&self) -> Result<ParsiDate> {
+        match self {
+            Origin::Parsi(date) => Ok(*date),
+            Origin::Gregorian(date) => ParsiDate::from_gregorian(*date)
+                .map_err(|e| map_mitra_error(e, "converting origin date")),
+        }
+    }
+
+    fn year(&self) -> i32 {
+        match self {
+            Origin::Parsi(date) => date.year(),
+            Origin::Gregorian(date) => date.year(),
+        }
+    }
+
+    /// The anniversary in calendar year `year` (of the origin's calendar), with a flag
+    /// telling whether the day had to be moved back to the end of a shorter month.
+    /// `None` if the date is outside the supported range.
+    fn in_year(&self, year: i32) -> Option<(ParsiDate, bool)> {
+        match self {
+            Origin::Parsi(origin) => {
+                let length = ParsiDate::days_in_month(year, origin.month());
+                let day = origin.day().min(length);
+                let date = ParsiDate::new(year, origin.month(), day).ok()?;
+                Some((date, day != origin.day()))
+            }
+            Origin::Gregorian(origin) => {
+                let (date, shifted) =
+                    match NaiveDate::from_ymd_opt(year, origin.month(), origin.day()) {
+                        Some(date) => (date, false),
+                        // 29 February in a common year.
+                        None => (
+                            NaiveDate::from_ymd_opt(year, origin.month(), origin.day() - 1)?,
+                            true,
+                        ),
+                    };
+                Some((ParsiDate::from_gregorian(date).ok()?, shifted))
+            }
+        }
+    }
+}
+
+/// Lists the next `count` anniversaries of `origin` that fall on or after `today`.
+/// The list is shorter if the supported year range ends first.
+pub fn upcoming(origin: &Origin, today: &ParsiDate, count: usize) -> Result<Vec<Anniversary>> {
+    let today_gregorian = today
+        .to_gregorian()
+        .map_err(|e| map_mitra_error(e, "converting today's date"))?;
+    let current_year = match origin {
+        Origin::Parsi(_) => today.year(),
+        Origin::Gregorian(_) => today_gregorian.year(),
+    };
+
+    let mut anniversaries = Vec::with_capacity(count);
+    let mut year = (origin.year() + 1).max(current_year);
+    while anniversaries.len() < count {
+        // Counting starts at the current year, so only the end of the range is reachable.
+        let Some((date, shifted)) = origin.in_year(year) else {
+            break;
+        };
+        let days_until = signed_days_between(today, &date)?;
+        if days_until >= 0 {
+            anniversaries.push(Anniversary {
+                years: year - origin.year(),
+                date,
+                days_until,
+                shifted,
+            });
+        }
+        year += 1;
+    }
+    if anniversaries.is_empty() && count > 0 {
+        let range = supported_range();
+        bail!(
+            "Error: No anniversaries of {} fall within the supported range {}..={}.",
+            origin.parsi_date()?,
+            range.start(),
+            range.end()
+        );
+    }
+    Ok(anniversaries)
+}
@@ -0,0 +1,90 @@
+//  ~/src/applet.rs
+//
+//  * Copyright (C) 2024–2025 Parsicore <parsicore.dev@gmail.com>
+//  * Package : mitra
+//  * License : Apache-2.0
+//  * Version : 2.3.0
+//  * URL     : https://github.com/parsicore/Mitra
+//  * Sign: mitra-20250419-bd5fbe728fa2-5836b45f25d83501625cc5529193d5f0
+//
+//! Builds the JSON data feed consumed by desktop panel applets (GNOME Shell
+//! extension, Plasma widget): today's date, the next upcoming event, and the
+//! current month's grid.
+
+use crate::events::{self, NEXT_EVENT_WINDOW_DAYS};
+use crate::month_grid::MonthGrid;
+use crate::utils::{map_mitra_error, signed_days_between};
+use crate::weekday::WeekStart;
+use crate::zodiac;
+use anyhow::Result;
+use parsidate::ParsiDate;
+use serde::Serialize;
+
+/// The complete feed document.
+#[derive(Serialize, Debug)]
+pub struct AppletFeed {
+    pub today: TodayInfo,
+    pub next_event: Option<NextEvent>,
+    /// The current month, Saturday first.
+    pub month: MonthGrid,
+}
+
+/// Summary of the current day, for the panel label.
+#[derive(Serialize, Debug)]
+pub struct TodayInfo {
+    pub date: String,
+    pub year: i32,
+    pub month: u32,
+    pub day: u32,
+    pub month_name: String,
+    pub weekday: String,
+    pub gregorian: String,
+    pub is_holiday: bool,
+    /// The solar zodiac sign of the current month (e.g., "برج حمل").
+    pub zodiac_sign: String,
+}
+
+/// The nearest upcoming day (today included) that has events.
+#[derive(Serialize, Debug)]
+pub struct NextEvent {
+    pub date: String,
+    pub days_until: i64,
+    pub holiday: bool,
+    pub titles: Vec<String>,
+}
+
+/// Builds the feed for the given "today".
+pub fn build_feed(today: &ParsiDate) -> Result<AppletFeed> {
+    let gregorian = today
+        .to_gregorian()
+        .map_err(|e| map_mitra_error(e, "converting to Gregorian"))?;
+    let weekday = today
+        .weekday()
+        .map_err(|e| map_mitra_error(e, "getting weekday"))?;
+
+    let next_event = match events::next_events_from(today, NEXT_EVENT_WINDOW_DAYS) {
+        Some((date, day_events)) => Some(NextEvent {
+            date: date.to_string(),
+            days_until: signed_days_between(today, &date)?,
+            holiday: day_events.iter().any(|e| e.holiday),
+            titles: day_events.into_iter().map(|e| e.title).collect(),
+        }),
+        None => None,
+    };
+
+    Ok(AppletFeed {
+        today: TodayInfo {
+            date: today.to_string(),
+            year: today.year(),
+            month: today.month(),
+            day: today.day(),
+            month_name: today.format("%B"),
+            weekday,
+            gregorian: gregorian.format("%Y-%m-%d").to_string(),
+            is_holiday: events::is_holiday(today),
+            zodiac_sign: zodiac::sign_name(today.month()),
+        },
+        next_event,
+        month: MonthGrid::new(today.year(), today.month(), today, WeekStart::Sat)?,
+    })
+}
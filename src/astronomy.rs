@@ -0,0 +1,130 @@
+//  ~/src/astronomy.rs
+//
+//  * Copyright (C) 2024–2025 Parsicore <parsicore.dev@gmail.com>
+//  * Package : mitra
+//  * License : Apache-2.0
+//  * Version : 2.3.0
+//  * URL     : https://github.com/parsicore/Mitra
+//  * Sign: mitra-20250419-bd5fbe728fa2-5836b45f25d83501625cc5529193d5f0
+//
+//! The astronomical Nowruz (Sāl Tahvil): the moment of the March equinox that starts
+//! each Parsi year, in Tehran time.
+//!
+//! The equinox follows Meeus, *Astronomical Algorithms* (2nd ed.), chapter 27: a mean
+//! equinox polynomial corrected by 24 periodic terms, good to about a minute for
+//! Gregorian years 1000-3000. The result is in Terrestrial Time and is brought to UT
+//! with the Espenak-Meeus ΔT polynomials, which are accurate for 1900-2150 and only
+//! a rough long-term fit outside it.
+
+use crate::timezone::ParsiZonedDateTime;
+use anyhow::{Context, Result, bail};
+use chrono::{DateTime, Utc};
+use chrono_tz::Asia::Tehran;
+use std::ops::RangeInclusive;
+
+/// Parsi years whose Nowruz can be computed (Gregorian 1000-3000, Meeus's range).
+const SUPPORTED_YEARS: RangeInclusive<i32> = 379..=2379;
+
+/// Years between the Parsi and Gregorian calendars at the March equinox.
+const GREGORIAN_OFFSET: i32 = 621;
+
+/// Julian Day of the Unix epoch (1970-01-01 00:00 UTC).
+const JD_UNIX_EPOCH: f64 = 2_440_587.5;
+
+/// Periodic terms for the equinox correction: amplitude, phase (°), rate (°/century).
+const PERIODIC_TERMS: [(f64, f64, f64); 24] = [
+    (485.0, 324.96, 1934.136),
+    (203.0, 337.23, 32964.467),
+    (199.0, 342.08, 20.186),
+    (182.0, 27.85, 445267.112),
+    (156.0, 73.14, 45036.886),
+    (136.0, 171.52, 22518.443),
+    (77.0, 222.54, 65928.934),
+    (74.0, 296.72, 3034.906),
+    (70.0, 243.58, 9037.513),
+    (58.0, 119.81, 33718.147),
+    (52.0, 297.17, 150.678),
+    (50.0, 21.02, 2281.226),
+    (45.0, 247.54, 29929.562),
+    (44.0, 325.15, 31555.956),
+    (29.0, 60.93, 4443.417),
+    (18.0, 155.12, 67555.328),
+    (17.0, 288.79, 4562.452),
+    (16.0, 198.04, 62894.029),
+    (14.0, 199.76, 31436.921),
+    (12.0, 95.39, 14577.848),
+    (12.0, 287.11, 31931.756),
+    (12.0, 320.81, 34777.259),
+    (9.0, 227.73, 1222.114),
+    (8.0, 15.45, 16859.074),
+];
+
+/// The moment of Sāl Tahvil that begins Parsi `year`, in Tehran time, to the second.
+pub fn get_nowruz_moment(year: i32) -> Result<ParsiZonedDateTime> {
+    if !SUPPORTED_YEARS.contains(&year) {
+        bail!(
+            "Error: Nowruz can only be computed for years {}..={}, got {}.",
+            SUPPORTED_YEARS.start(),
+            SUPPORTED_YEARS.end(),
+            year
+        );
+    }
+    ParsiZonedDateTime::from_utc(march_equinox(year + GREGORIAN_OFFSET)?, Tehran)
+}
+
+/// The March equinox of a Gregorian year, in UTC rounded to the second.
+fn march_equinox(gregorian_year: i32) -> Result<DateTime<Utc>> {
+    let y = (f64::from(gregorian_year) - 2000.0) / 1000.0;
+    let jde0 = 2_451_623.809_84 + 365_242.374_04 * y + 0.05169 * y.powi(2)
+        - 0.00411 * y.powi(3)
+        - 0.00057 * y.powi(4);
+    let t = (jde0 - 2_451_545.0) / 36_525.0;
+    let w = (35_999.373 * t - 2.47).to_radians();
+    let lambda = 1.0 + 0.0334 * w.cos() + 0.0007 * (2.0 * w).cos();
+    let s: f64 = PERIODIC_TERMS
+        .iter()
+        .map(|(a, b, c)| a * (b + c * t).to_radians().cos())
+        .sum();
+    let jde = jde0 + 0.00001 * s / lambda;
+
+    let seconds = (jde - JD_UNIX_EPOCH) * 86_400.0 - delta_t(f64::from(gregorian_year) + 0.2);
+    DateTime::from_timestamp(seconds.round() as i64, 0)
+        .with_context(|| format!("Error: The equinox of {} is out of range.", gregorian_year))
+}
+
+/// ΔT (TT - UT) in seconds at decimal year `year`.
+fn delta_t(year: f64) -> f64 {
+    let long_term = |year: f64| -20.0 + 32.0 * ((year - 1820.0) / 100.0).powi(2);
+    match year {
+        y if (1900.0..1920.0).contains(&y) => {
+            let t = y - 1900.0;
+            -2.79 + 1.494119 * t - 0.0598939 * t.powi(2) + 0.0061966 * t.powi(3)
+                - 0.000197 * t.powi(4)
+        }
+        y if (1920.0..1941.0).contains(&y) => {
+            let t = y - 1920.0;
+            21.20 + 0.84493 * t - 0.076100 * t.powi(2) + 0.0020936 * t.powi(3)
+        }
+        y if (1941.0..1961.0).contains(&y) => {
+            let t = y - 1950.0;
+            29.07 + 0.407 * t - t.powi(2) / 233.0 + t.powi(3) / 2547.0
+        }
+        y if (1961.0..1986.0).contains(&y) => {
+            let t = y - 1975.0;
+            45.45 + 1.067 * t - t.powi(2) / 260.0 - t.powi(3) / 718.0
+        }
+        y if (1986.0..2005.0).contains(&y) => {
+            let t = y - 2000.0;
+            63.86 + 0.3345 * t - 0.060374 * t.powi(2)
+                + 0.0017275 * t.powi(3)
+                + 0.000651814 * t.powi(4)
+                + 0.00002373599 * t.powi(5)
+        }
+        y if (2005.0..2050.0).contains(&y) => {
+            let t = y - 2000.0;
+            62.92 + 0.32217 * t + 0.005589 * t.powi(2)
+        }
+        y if (2050.0..2150.0).contains(&y) => long_term(y) - 0.5628 * (2150.0 - y),
+        y => long_term(y),
+    }
+}
@@ -0,0 +1,147 @@
+//  ~/src/batch.rs
+//
+//  * Copyright (C) 2024–2025 Parsicore <parsicore.dev@gmail.com>
+//  * Package : mitra
+//  * License : Apache-2.0
+//  * Version : 2.3.0
+//  * URL     : https://github.com/parsicore/Mitra
+//  * Sign: mitra-20250419-bd5fbe728fa2-5836b45f25d83501625cc5529193d5f0
+//
+//! Line-by-line batch processing for the `batch` command.
+//!
+//! Input is streamed one line at a time with a reused buffer and output is written
+//! as each line is converted, so memory use stays flat regardless of input size.
+
+use crate::cli::BatchOperation;
+use crate::info::DateInfo;
+use crate::utils::{map_mitra_error, parse_gregorian_input, parse_input_datetime_or_date};
+#[cfg(feature = "lookup-tables")]
+use crate::year_table;
+use anyhow::{Context, Result, bail};
+use chrono::NaiveDateTime;
+#[cfg(feature = "lookup-tables")]
+use chrono::Timelike;
+use parsidate::ParsiDateTime;
+use std::io::{BufRead, Write};
+
+/// Counts of processed and failed lines, reported after a run.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct BatchSummary {
+    pub converted: u64,
+    pub failed: u64,
+}
+
+/// Applies `operation` to every non-blank line of `reader`, writing one result line per input line.
+///
+/// Without `skip_errors`, the first failing line stops the run with an error naming its line number.
+/// With `skip_errors`, failures are reported on stderr (`line N: ...`) and processing continues.
+pub fn run<R: BufRead, W: Write>(
+    operation: BatchOperation,
+    mut reader: R,
+    mut writer: W,
+    skip_errors: bool,
+) -> Result<BatchSummary> {
+    let mut summary = BatchSummary::default();
+    let mut line = String::new();
+    let mut line_number: u64 = 0;
+
+    loop {
+        line.clear();
+        let bytes = reader
+            .read_line(&mut line)
+            .with_context(|| format!("Failed to read input line {}", line_number + 1))?;
+        if bytes == 0 {
+            break; // EOF
+        }
+        line_number += 1;
+
+        let input = line.trim();
+        if input.is_empty() {
+            continue;
+        }
+
+        match convert_line(operation, input) {
+            Ok(output) => {
+                writeln!(writer, "{}", output).context("Failed to write output")?;
+                summary.converted += 1;
+            }
+            Err(e) if skip_errors => {
+                eprintln!("line {}: {:#}", line_number, e);
+                summary.failed += 1;
+            }
+            Err(e) => bail!("Error on input line {}: {:#}", line_number, e),
+        }
+    }
+
+    writer.flush().context("Failed to flush output")?;
+    Ok(summary)
+}
+
+/// Converts a single trimmed input line.
+fn convert_line(operation: BatchOperation, input: &str) -> Result<String> {
+    match operation {
+        BatchOperation::ToGregorian => {
+            let (pdt, was_datetime) = parse_input_datetime_or_date(input)?;
+            let gregorian = to_gregorian(&pdt)?;
+            let pattern = if was_datetime {
+                "%Y-%m-%d %H:%M:%S"
+            } else {
+                "%Y-%m-%d"
+            };
+            Ok(gregorian.format(pattern).to_string())
+        }
+        BatchOperation::FromGregorian => {
+            let (ndt, was_datetime) = parse_gregorian_input(input)?;
+            let pdt = from_gregorian(ndt)?;
+            Ok(if was_datetime {
+                pdt.to_string()
+            } else {
+                pdt.date().to_string()
+            })
+        }
+        BatchOperation::Weekday => {
+            let (pdt, _) = parse_input_datetime_or_date(input)?;
+            pdt.date()
+                .weekday()
+                .map_err(|e| map_mitra_error(e, "getting weekday"))
+        }
+        BatchOperation::Info => {
+            let (pdt, was_datetime) = parse_input_datetime_or_date(input)?;
+            let info = DateInfo::new(input, &pdt, was_datetime)?;
+            serde_json::to_string(&info).context("Failed to serialize date info to JSON")
+        }
+    }
+}
+
+/// Converts to Gregorian through the year-start table (see `year_table`).
+#[cfg(feature = "lookup-tables")]
+fn to_gregorian(pdt: &ParsiDateTime) -> Result<NaiveDateTime> {
+    year_table::to_gregorian(&pdt.date())
+        .and_hms_opt(pdt.hour(), pdt.minute(), pdt.second())
+        .with_context(|| format!("Error: Invalid time in {}.", pdt))
+}
+
+#[cfg(not(feature = "lookup-tables"))]
+fn to_gregorian(pdt: &ParsiDateTime) -> Result<NaiveDateTime> {
+    pdt.to_gregorian()
+        .map_err(|e| map_mitra_error(e, "converting to Gregorian"))
+}
+
+/// Converts from Gregorian through the year-start table, falling back to `parsidate`
+/// outside the supported range so the error matches the non-table build.
+#[cfg(feature = "lookup-tables")]
+fn from_gregorian(ndt: NaiveDateTime) -> Result<ParsiDateTime> {
+    match year_table::from_gregorian(ndt.date()) {
+        Some(date) => {
+            ParsiDateTime::from_date_and_time(date, ndt.hour(), ndt.minute(), ndt.second())
+                .map_err(|e| map_mitra_error(e, "converting from Gregorian"))
+        }
+        None => ParsiDateTime::from_gregorian(ndt)
+            .map_err(|e| map_mitra_error(e, "converting from Gregorian")),
+    }
+}
+
+#[cfg(not(feature = "lookup-tables"))]
+fn from_gregorian(ndt: NaiveDateTime) -> Result<ParsiDateTime> {
+    ParsiDateTime::from_gregorian(ndt).map_err(|e| map_mitra_error(e, "converting from Gregorian"))
+}
@@ -0,0 +1,109 @@
+//  ~/src/business_days.rs
+//
+//  * Copyright (C) 2024–2025 Parsicore <parsicore.dev@gmail.com>
+//  * Package : mitra
+//  * License : Apache-2.0
+//  * Version : 2.3.0
+//  * URL     : https://github.com/parsicore/Mitra
+//  * Sign: mitra-20250419-bd5fbe728fa2-5836b45f25d83501625cc5529193d5f0
+//
+//! Working-day arithmetic. A business day is any day that is neither a Friday
+//! nor an official holiday from the embedded event data. `add_business_days`,
+//! `sub_business_days`, and `business_days_between` back the `--business-days`
+//! options of `add`, `sub`, and `diff`, e.g. for payment due dates.
+
+use crate::events;
+use crate::range::ParsiDateRangeExt;
+use crate::utils::map_mitra_error;
+use crate::weekday::Weekday;
+use anyhow::{Result, anyhow};
+use parsidate::ParsiDate;
+
+/// How a day counts for working-day arithmetic.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DayKind {
+    Workday,
+    Friday,
+    /// An official holiday (takes precedence over Friday).
+    Holiday,
+}
+
+/// Classifies `date` as a workday, a Friday, or an official holiday.
+pub fn classify(date: &ParsiDate) -> Result<DayKind> {
+    Ok(if events::is_holiday(date) {
+        DayKind::Holiday
+    } else if Weekday::of(date)? == Weekday::Friday {
+        DayKind::Friday
+    } else {
+        DayKind::Workday
+    })
+}
+
+/// Returns `true` if `date` is neither a Friday nor an official holiday.
+pub fn is_business_day(date: &ParsiDate) -> Result<bool> {
+    Ok(classify(date)? == DayKind::Workday)
+}
+
+/// Returns `date` itself if it is a business day, otherwise the next one.
+pub fn next_business_day(date: &ParsiDate) -> Result<ParsiDate> {
+    let mut day = *date;
+    while !is_business_day(&day)? {
+        day = day
+            .add_days(1)
+            .map_err(|e| map_mitra_error(e, "finding the next business day"))?;
+    }
+    Ok(day)
+}
+
+/// Counts the business days in `first..=last` (zero if `last` is before `first`).
+pub fn count_business_days(first: &ParsiDate, last: &ParsiDate) -> Result<u32> {
+    let mut count = 0;
+    for day in first.iter_to(*last) {
+        if is_business_day(&day)? {
+            count += 1;
+        }
+    }
+    Ok(count)
+}
+
+/// Moves `days` business days forward from `date` (backward if negative), skipping
+/// Fridays and official holidays. `date` itself need not be a business day; with
+/// `days == 0` it is returned unchanged.
+pub fn add_business_days(date: &ParsiDate, days: i64) -> Result<ParsiDate> {
+    let step = if days < 0 { -1 } else { 1 };
+    let mut day = *date;
+    for _ in 0..days.unsigned_abs() {
+        day = day
+            .add_days(step)
+            .map_err(|e| map_mitra_error(e, "adding business days"))?;
+        while !is_business_day(&day)? {
+            day = day
+                .add_days(step)
+                .map_err(|e| map_mitra_error(e, "adding business days"))?;
+        }
+    }
+    Ok(day)
+}
+
+/// Moves `days` business days back from `date`, skipping Fridays and official holidays.
+pub fn sub_business_days(date: &ParsiDate, days: u64) -> Result<ParsiDate> {
+    let days = i64::try_from(days)
+        .map_err(|_| anyhow!("Error: Business-day count {} is too large.", days))?;
+    add_business_days(date, -days)
+}
+
+/// The number of business days between `from` (exclusive) and `to` (inclusive);
+/// negative if `to` is before `from`. This is the inverse of `add_business_days`
+/// whenever `to` is a business day.
+pub fn business_days_between(from: &ParsiDate, to: &ParsiDate) -> Result<i64> {
+    let (first, last, sign) = if to < from {
+        (to, from, -1)
+    } else {
+        (from, to, 1)
+    };
+    let mut count = i64::from(count_business_days(first, last)?);
+    if is_business_day(from)? {
+        count -= 1;
+    }
+    Ok(sign * count)
+}
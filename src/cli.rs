@@ -9,7 +9,10 @@
 //
 //! Defines the command-line interface structure using clap.
 
-use clap::{Parser, Subcommand, ValueEnum};
+use clap::{ArgAction, Parser, Subcommand, ValueEnum};
+
+use crate::info::InfoField;
+pub use crate::weekday::WeekStart;
 
 // Top-level CLI arguments structure
 #[derive(Parser, Debug)]
@@ -22,76 +25,142 @@ use clap::{Parser, Subcommand, ValueEnum};
 pub struct Cli {
     #[command(subcommand)]
     pub command: Option<Commands>, // Optional command, defaults to 'now'
+
+    /// Increase diagnostic output on stderr (-v: info, -vv: debug, -vvv: trace).
+    #[arg(short, long, action = ArgAction::Count, global = true)]
+    pub verbose: u8,
+
+    /// Suppress all diagnostic output on stderr, including warnings.
+    #[arg(short, long, global = true, conflicts_with = "verbose")]
+    pub quiet: bool,
+
+    /// Print bare values without labels or decorations, for use in scripts.
+    /// Honored by `info` (the date, or the `--field` value), `weekday`, `diff`, `is-leap`,
+    /// and `is-workday`.
+    #[arg(long, global = true)]
+    pub raw: bool,
+
+    /// Reject lenient date inputs: surrounding whitespace, unpadded or short (two-digit) years,
+    /// trailing characters, and natural-language phrases. Errors report the offending position.
+    #[arg(long, global = true)]
+    pub strict: bool,
+
+    /// Write digits in Persian (۱۴۰۳/۰۵/۰۲) in the human-readable output of
+    /// `now`, `cal`, `info`, `format`, and date results such as `add`. JSON stays ASCII.
+    #[arg(long, global = true)]
+    pub persian_digits: bool,
 }
 
 // Enum defining the available subcommands
 #[derive(Subcommand, Debug)]
 pub enum Commands {
     /// Display the current Parsi date and time (default action).
-    Now,
+    Now {
+        /// Show the time in this city or IANA time zone (e.g., UTC, tehran, Europe/Berlin), with its UTC offset.
+        #[arg(long)]
+        tz: Option<String>,
+    },
+
+    /// Display today's Parsi date, optionally with the historical occasions on this day.
+    Today {
+        /// Also list notable historical occasions that happened on today's month/day.
+        #[arg(long)]
+        occasions: bool,
+
+        /// Only show occasions in this category (e.g., history, culture, science, sport, disaster).
+        #[arg(short, long, requires = "occasions")]
+        category: Option<String>,
+    },
+
+    /// Print the current Parsi date and time as a filename-safe stamp (e.g., for backups and log rotation).
+    Stamp {
+        /// Format pattern. Defaults to $MITRA_STAMP_PATTERN, or "%Y%m%d-%H%M%S" if unset.
+        #[arg(short, long)]
+        pattern: Option<String>,
+    },
 
-    /// Add a duration (days, months, years, hours, minutes, seconds) to a given date/datetime.
+    /// Convert a Unix timestamp to a Parsi datetime, or a Parsi date/datetime to a Unix timestamp.
+    Timestamp {
+        /// Seconds since 1970-01-01 00:00:00 UTC, or a Parsi date/datetime to convert back.
+        /// Defaults to the current timestamp.
+        #[arg(allow_hyphen_values = true)]
+        value: Option<String>,
+
+        /// City or IANA time zone of the Parsi side (defaults to Asia/Tehran).
+        #[arg(long)]
+        tz: Option<String>,
+    },
+
+    /// Add a duration (days, months, years, hours, minutes, seconds, business days) to a given date/datetime.
     /// Only one duration unit can be specified at a time.
     Add {
         /// Base date (YYYY/MM/DD or YYYY-MM-DD) or datetime (YYYY/MM/DD HH:MM:SS or YYYY-MM-DDTHH:MM:SS).
         base_datetime: String,
 
         // Duration units - mutually exclusive using clap's `conflicts_with_all`
-        #[arg(long, conflicts_with_all = ["months", "years", "hours", "minutes", "seconds"])]
+        #[arg(long, conflicts_with_all = ["months", "years", "hours", "minutes", "seconds", "business_days"])]
         /// Number of days to add (e.g., 5 or -3).
         days: Option<i64>,
 
-        #[arg(long, conflicts_with_all = ["days", "years", "hours", "minutes", "seconds"])]
+        #[arg(long, conflicts_with_all = ["days", "years", "hours", "minutes", "seconds", "business_days"])]
         /// Number of months to add (e.g., 2 or -1). Handles day clamping.
         months: Option<i32>,
 
-        #[arg(long, conflicts_with_all = ["days", "months", "hours", "minutes", "seconds"])]
+        #[arg(long, conflicts_with_all = ["days", "months", "hours", "minutes", "seconds", "business_days"])]
         /// Number of years to add (e.g., 1 or -10). Handles leap day adjustment.
         years: Option<i32>,
 
-        #[arg(long, conflicts_with_all = ["days", "months", "years", "minutes", "seconds"])]
+        #[arg(long, conflicts_with_all = ["days", "months", "years", "minutes", "seconds", "business_days"])]
         /// Number of hours to add (e.g., 3 or -1). Uses precise duration arithmetic.
         hours: Option<i64>,
 
-        #[arg(long, conflicts_with_all = ["days", "months", "years", "hours", "seconds"])]
+        #[arg(long, conflicts_with_all = ["days", "months", "years", "hours", "seconds", "business_days"])]
         /// Number of minutes to add (e.g., 30 or -15). Uses precise duration arithmetic.
         minutes: Option<i64>,
 
-        #[arg(long, conflicts_with_all = ["days", "months", "years", "hours", "minutes"])]
+        #[arg(long, conflicts_with_all = ["days", "months", "years", "hours", "minutes", "business_days"])]
         /// Number of seconds to add (e.g., 90 or -45). Uses precise duration arithmetic.
         seconds: Option<i64>,
+
+        #[arg(long, conflicts_with_all = ["days", "months", "years", "hours", "minutes", "seconds"])]
+        /// Number of business days to add (e.g., 10 or -2), skipping Fridays and official holidays.
+        business_days: Option<i64>,
     },
 
-    /// Subtract a duration (days, months, years, hours, minutes, seconds) from a given date/datetime.
+    /// Subtract a duration (days, months, years, hours, minutes, seconds, business days) from a given date/datetime.
     /// Only one duration unit can be specified at a time.
     Sub {
         /// Base date (YYYY/MM/DD or YYYY-MM-DD) or datetime (YYYY/MM/DD HH:MM:SS or YYYY-MM-DDTHH:MM:SS).
         base_datetime: String,
 
         // Duration units - mutually exclusive
-        #[arg(long, conflicts_with_all = ["months", "years", "hours", "minutes", "seconds"])]
+        #[arg(long, conflicts_with_all = ["months", "years", "hours", "minutes", "seconds", "business_days"])]
         /// Number of days to subtract (must be non-negative, e.g., 5).
         days: Option<u64>,
 
-        #[arg(long, conflicts_with_all = ["days", "years", "hours", "minutes", "seconds"])]
+        #[arg(long, conflicts_with_all = ["days", "years", "hours", "minutes", "seconds", "business_days"])]
         /// Number of months to subtract (must be non-negative, e.g., 2). Handles day clamping.
         months: Option<u32>,
 
-        #[arg(long, conflicts_with_all = ["days", "months", "hours", "minutes", "seconds"])]
+        #[arg(long, conflicts_with_all = ["days", "months", "hours", "minutes", "seconds", "business_days"])]
         /// Number of years to subtract (must be non-negative, e.g., 1). Handles leap day adjustment.
         years: Option<u32>,
 
-        #[arg(long, conflicts_with_all = ["days", "months", "years", "minutes", "seconds"])]
+        #[arg(long, conflicts_with_all = ["days", "months", "years", "minutes", "seconds", "business_days"])]
         /// Number of hours to subtract (must be non-negative, e.g., 3). Uses precise duration arithmetic.
         hours: Option<u64>,
 
-        #[arg(long, conflicts_with_all = ["days", "months", "years", "hours", "seconds"])]
+        #[arg(long, conflicts_with_all = ["days", "months", "years", "hours", "seconds", "business_days"])]
         /// Number of minutes to subtract (must be non-negative, e.g., 30). Uses precise duration arithmetic.
         minutes: Option<u64>,
 
-        #[arg(long, conflicts_with_all = ["days", "months", "years", "hours", "minutes"])]
+        #[arg(long, conflicts_with_all = ["days", "months", "years", "hours", "minutes", "business_days"])]
         /// Number of seconds to subtract (must be non-negative, e.g., 90). Uses precise duration arithmetic.
         seconds: Option<u64>,
+
+        #[arg(long, conflicts_with_all = ["days", "months", "years", "hours", "minutes", "seconds"])]
+        /// Number of business days to subtract (must be non-negative, e.g., 10), skipping Fridays and official holidays.
+        business_days: Option<u64>,
     },
 
     /// Format a given date/datetime string using a predefined style or a custom pattern.
@@ -109,30 +178,170 @@ pub enum Commands {
         pattern: Option<String>,
     },
 
-    /// Calculate the absolute difference in days between two dates/datetimes.
+    /// Calculate the absolute difference between two dates/datetimes, in days and as years/months/days/time.
     Diff {
         /// First date/datetime string.
         datetime1: String,
         /// Second date/datetime string.
         datetime2: String,
+        /// Count business days (excluding Fridays and official holidays) instead of calendar days.
+        #[arg(long)]
+        business_days: bool,
     },
 
-    /// Get the Persian weekday name for a given date.
-    Weekday {
-        /// Date string (YYYY/MM/DD or YYYY-MM-DD). Time part is ignored if present.
+    /// Show how many days remain until a date (e.g., "۴۵ روز مانده").
+    Until {
+        /// The target date (YYYY/MM/DD or YYYY-MM-DD). Time part is ignored if present.
         date_string: String,
     },
 
+    /// Show how many days have passed since a date (e.g., "۱۲ روز گذشته").
+    Since {
+        /// The reference date (YYYY/MM/DD or YYYY-MM-DD). Time part is ignored if present.
+        date_string: String,
+    },
+
+    /// Get the Persian weekday name for one or more dates, or for every day in a range.
+    /// With more than one date, each line is printed as `date → weekday`.
+    Weekday {
+        /// Date strings (YYYY/MM/DD or YYYY-MM-DD). Time part is ignored if present.
+        #[arg(required_unless_present = "from", conflicts_with_all = ["from", "to"])]
+        date_strings: Vec<String>,
+
+        /// Start of an inclusive date range. Requires --to.
+        #[arg(long, requires = "to")]
+        from: Option<String>,
+
+        /// End of an inclusive date range. Requires --from.
+        #[arg(long, requires = "from")]
+        to: Option<String>,
+    },
+
+    /// Print every date from START through END (or COUNT dates from START), one per line.
+    Range {
+        /// First date (YYYY/MM/DD or YYYY-MM-DD).
+        start: String,
+
+        /// Last date, inclusive. Without it, --count is required.
+        end: Option<String>,
+
+        /// Distance between dates: a number followed by d (days), w (weeks), or m (months).
+        #[arg(short, long, value_name = "STEP", default_value = "1d")]
+        every: String,
+
+        /// Print at most N dates.
+        #[arg(short = 'n', long, value_name = "N", required_unless_present = "end")]
+        count: Option<usize>,
+
+        /// List the dates from the end backwards.
+        #[arg(short, long, requires = "end")]
+        reverse: bool,
+    },
+
+    /// List the dates of a recurrence rule (iCalendar RRULE syntax on the Parsi calendar), one per line.
+    Recur {
+        /// The rule, e.g. "FREQ=MONTHLY;BYDAY=-1FR" (the last Friday of each month).
+        rule: String,
+
+        /// First date of the series (YYYY/MM/DD or YYYY-MM-DD).
+        start: String,
+
+        /// Last date to list, inclusive. Defaults to 100 years after START.
+        end: Option<String>,
+
+        /// Print at most N dates.
+        #[arg(short = 'n', long, value_name = "N")]
+        count: Option<usize>,
+
+        /// Print the dates as an iCalendar (.ics) event instead, for calendar apps.
+        #[arg(long)]
+        ics: bool,
+
+        /// Title of the exported event.
+        #[arg(long, requires = "ics")]
+        summary: Option<String>,
+    },
+
+    /// List the dates of the events in an iCalendar (.ics) file (DTSTART, RRULE, RDATE, EXDATE) as Parsi dates.
+    Ics {
+        /// The calendar file, or - for stdin.
+        file: std::path::PathBuf,
+
+        /// First date to list (YYYY/MM/DD or YYYY-MM-DD). Defaults to today.
+        #[arg(long)]
+        from: Option<String>,
+
+        /// Last date to list, inclusive. Defaults to one year after the first.
+        #[arg(long)]
+        to: Option<String>,
+
+        /// City or IANA time zone for UTC times in the file (defaults to Asia/Tehran).
+        #[arg(long)]
+        tz: Option<String>,
+    },
+
     /// Convert a Parsi date/datetime to Gregorian.
     ToGregorian {
         /// Parsi date (YYYY/MM/DD or YYYY-MM-DD) or datetime (YYYY/MM/DD HH:MM:SS or YYYY-MM-DDTHH:MM:SS).
         parsi_datetime: String,
+
+        /// City or IANA time zone of the input's wall-clock time. The output then includes its UTC offset.
+        #[arg(long)]
+        tz: Option<String>,
+
+        /// Convert the instant to this city or time zone (defaults to --tz).
+        #[arg(long, requires = "tz")]
+        to_tz: Option<String>,
+
+        /// Print the result as an RFC 3339 timestamp (e.g., 2025-03-16T06:00:00Z).
+        #[arg(long, requires = "tz")]
+        rfc3339: bool,
     },
 
     /// Convert a Gregorian date/datetime to Parsi.
     FromGregorian {
-        /// Gregorian date (YYYY-MM-DD) or datetime (YYYY-MM-DD HH:MM:SS or YYYY-MM-DDTHH:MM:SS).
+        /// Gregorian date (YYYY-MM-DD), datetime (YYYY-MM-DD HH:MM:SS or YYYY-MM-DDTHH:MM:SS), or RFC 3339 timestamp.
         gregorian_datetime: String,
+
+        /// City or IANA time zone of the input's wall-clock time. The output then includes its UTC offset.
+        #[arg(long)]
+        tz: Option<String>,
+
+        /// Convert the instant to this city or time zone (defaults to --tz).
+        #[arg(long, requires = "tz")]
+        to_tz: Option<String>,
+    },
+
+    /// Convert a Parsi date/datetime to the Julian (old-style) calendar.
+    ToJulian {
+        /// Parsi date (YYYY/MM/DD or YYYY-MM-DD) or datetime (YYYY/MM/DD HH:MM:SS or YYYY-MM-DDTHH:MM:SS).
+        parsi_datetime: String,
+    },
+
+    /// Convert a Julian (old-style) date/datetime to Parsi, e.g. for documents dated before the Gregorian reform.
+    FromJulian {
+        /// Julian date (YYYY-MM-DD) or datetime (YYYY-MM-DD HH:MM:SS or YYYY-MM-DDTHH:MM:SS).
+        julian_datetime: String,
+    },
+
+    /// Convert a Parsi (or, with --gregorian, Gregorian) date/datetime to the tabular Hijri (lunar) calendar.
+    ToHijri {
+        /// Parsi date (YYYY/MM/DD or YYYY-MM-DD) or datetime (YYYY/MM/DD HH:MM:SS or YYYY-MM-DDTHH:MM:SS).
+        datetime_string: String,
+
+        /// Read the input as a Gregorian date/datetime instead.
+        #[arg(short, long)]
+        gregorian: bool,
+    },
+
+    /// Convert a tabular Hijri (lunar) date/datetime to Parsi (or, with --gregorian, to Gregorian).
+    FromHijri {
+        /// Hijri date (YYYY/MM/DD or YYYY-MM-DD) or datetime (YYYY/MM/DD HH:MM:SS or YYYY-MM-DDTHH:MM:SS).
+        hijri_datetime: String,
+
+        /// Print the Gregorian date/datetime instead of the Parsi one.
+        #[arg(short, long)]
+        gregorian: bool,
     },
 
     /// Check if a given Parsi year is a leap year.
@@ -141,10 +350,110 @@ pub enum Commands {
         year: i32,
     },
 
+    /// Check whether a Parsi date is a workday, a Friday, or an official holiday.
+    IsWorkday {
+        /// Date to check (YYYY/MM/DD or YYYY-MM-DD). Defaults to today.
+        date_string: Option<String>,
+
+        /// Exit with status 2 on a Friday and 3 on an official holiday (0 on a workday), for cron and CI guards.
+        #[arg(long)]
+        fail_if_holiday: bool,
+    },
+
+    /// Show the academic year, term, week number, and instructional days left for a date.
+    Academic {
+        /// Date to check (YYYY/MM/DD or YYYY-MM-DD). Defaults to today.
+        date_string: Option<String>,
+
+        /// First day of the academic year and of the first term, as MM/DD or e.g. "1 مهر".
+        #[arg(long, value_name = "DAY", default_value = "07/01")]
+        term_start: String,
+
+        /// First day of the second term.
+        #[arg(long, value_name = "DAY", default_value = "11/01")]
+        second_term: String,
+
+        /// Last day of classes in the second term.
+        #[arg(long, value_name = "DAY", default_value = "03/31")]
+        classes_end: String,
+
+        /// Emit the result as a JSON document.
+        #[arg(long)]
+        json: bool,
+    },
+
+    /// List upcoming yearly anniversaries of a historical date, with weekday and days until each.
+    Anniversary {
+        /// The original date: Parsi (YYYY/MM/DD or YYYY-MM-DD), or Gregorian with --gregorian.
+        date_string: String,
+
+        /// Number of anniversaries to list.
+        #[arg(short, long, value_name = "COUNT", default_value_t = 10)]
+        n: usize,
+
+        /// Read the date as Gregorian and count anniversaries on the Gregorian calendar.
+        #[arg(short, long)]
+        gregorian: bool,
+    },
+
+    /// Count the leave days a date range consumes, excluding Fridays and official holidays.
+    Leave {
+        /// First day of leave (YYYY/MM/DD or YYYY-MM-DD).
+        start: String,
+
+        /// Last day of leave, inclusive.
+        end: String,
+
+        /// Also exclude Thursdays, for five-day work weeks.
+        #[arg(long)]
+        exclude_thursdays: bool,
+
+        /// Emit the summary and per-day breakdown as a JSON document.
+        #[arg(long)]
+        json: bool,
+    },
+
+    /// Show the current (or a given) instant as Parsi and Gregorian date/time in several cities.
+    Worldclock {
+        /// Parsi date or datetime, read as wall-clock time in the first city. Defaults to now.
+        datetime_string: Option<String>,
+
+        /// Comma-separated cities or IANA time zones (e.g., tehran,berlin,America/Toronto). The first is the reference.
+        #[arg(short, long, value_delimiter = ',', default_value = "tehran,utc")]
+        cities: Vec<String>,
+
+        /// Emit the rows as a JSON array.
+        #[arg(long)]
+        json: bool,
+    },
+
+    /// Run a command on a Parsi-calendar schedule (e.g., month boundaries, first workday), as a daemon.
+    Schedule {
+        /// Schedule expression YEAR-MONTH-DAY [HH:MM[:SS]]; DAY may be last, first-workday, or last-workday.
+        #[arg(long, value_name = "EXPR")]
+        at: String,
+
+        /// Shell command to run at each scheduled time.
+        #[arg(long, value_name = "CMD", required_unless_present = "next")]
+        exec: Option<String>,
+
+        /// Print the next N scheduled times and exit instead of running.
+        #[arg(long, value_name = "N", conflicts_with = "exec")]
+        next: Option<usize>,
+    },
+
     /// Display detailed information about a Parsi date/datetime.
     Info {
         /// Parsi date (YYYY/MM/DD or YYYY-MM-DD) or datetime (YYYY/MM/DD HH:MM:SS or YYYY-MM-DDTHH:MM:SS).
         datetime_string: String,
+
+        /// Emit a single JSON document (including the day's events) instead of the text block.
+        #[arg(long)]
+        json: bool,
+
+        /// Print only this value, without a label (e.g. `weekday`, `day-of-year`, `gregorian`).
+        #[arg(long, value_enum, conflicts_with = "json")]
+        field: Option<InfoField>,
     },
 
     /// Parse a date/datetime string using an explicit format pattern.
@@ -179,11 +488,160 @@ pub enum Commands {
         /// Display the calendar for the entire specified year.
         #[arg(short = 'y', long = "year", value_name = "YEAR", conflicts_with_all = ["month", "three"])]
         show_year: Option<i32>, // Takes the year as an argument
+
+        /// The weekday shown in the first column of each week.
+        #[arg(long, value_enum, default_value_t = WeekStart::Sat)]
+        first_day: WeekStart,
+
+        /// After the calendar, list each displayed month's events with dates and holiday markers.
+        #[arg(long)]
+        events: bool,
+    },
+    /// Emit the JSON data feed (today, next event, month grid) used by desktop panel applets.
+    AppletFeed {
+        /// Pretty-print the JSON instead of emitting a single line.
+        #[arg(long)]
+        pretty: bool,
     },
-    /// List events for a specific Parsi date.
+
+    /// Print a single status line (date, weekday, next event) for Waybar, Polybar, or i3blocks.
+    Statusbar {
+        /// Line template. Placeholders: {date}, {weekday}, {day}, {month}, {month_name}, {year}, {gregorian}, {next_event}.
+        #[arg(short, long, default_value = "{weekday} {date}")]
+        format: String,
+
+        /// Emit Waybar custom-module JSON (text, tooltip, class) instead of plain text.
+        #[arg(long)]
+        json: bool,
+
+        /// Keep running and print a fresh line every N seconds (for bars that read a persistent stream).
+        #[arg(short, long, value_name = "SECONDS")]
+        interval: Option<u64>,
+    },
+
+    /// Serve JSON-RPC 2.0 requests over stdin/stdout, one JSON message per line.
+    Rpc,
+
+    /// Print a dmenu/rofi-style selectable list of a month's days (or events); `--select` shows details for a chosen line.
+    Pick {
+        /// Month (1-12) to list. Defaults to the current month.
+        month: Option<u32>,
+
+        /// Year of the month to list. Defaults to the current year.
+        year: Option<i32>,
+
+        /// List one line per event instead of one line per day.
+        #[arg(short, long)]
+        events: bool,
+
+        /// A line previously printed by `pick`; prints the details of its date instead of the list.
+        #[arg(short, long, value_name = "LINE", conflicts_with_all = ["month", "year", "events"])]
+        select: Option<String>,
+    },
+
+    /// Convert dates read from stdin, one per line, streaming results to stdout.
+    Batch {
+        /// The operation to apply to each line.
+        #[arg(value_enum)]
+        operation: BatchOperation,
+
+        /// Report failing lines on stderr and keep going instead of stopping at the first error.
+        #[arg(long)]
+        skip_errors: bool,
+    },
+
+    /// Run internal consistency checks (embedded data, conversions) and report any problems.
+    Doctor,
+
+    /// Show the traditional animal year name (e.g., سال مار) for a Parsi year.
+    Zodiac {
+        /// The Parsi year (e.g., 1404). Defaults to the current year.
+        year: Option<i32>,
+    },
+
+    /// Show the solar zodiac sign (برج) of a Parsi date and the dates it spans.
+    Sign {
+        /// Date to check (YYYY/MM/DD or YYYY-MM-DD). Defaults to today.
+        date_string: Option<String>,
+    },
+
+    /// Print a table of each Parsi month in a year with its Gregorian start and end dates.
+    Map {
+        /// The Parsi year to map (e.g., 1404). Defaults to the current year.
+        #[arg(short, long)]
+        year: Option<i32>,
+    },
+
+    /// Export a year's calendar data (one row per day) for spreadsheets and databases.
+    Export {
+        /// The Parsi year to export (e.g., 1404). Defaults to the current year.
+        #[arg(short, long)]
+        year: Option<i32>,
+
+        /// An inclusive range of Parsi years to export instead of a single year (e.g., 1390..1420).
+        #[arg(long, value_name = "START..END", conflicts_with = "year")]
+        year_range: Option<String>,
+
+        /// The output format.
+        #[arg(short, long, value_enum, default_value_t = ExportFormat::Csv)]
+        format: ExportFormat,
+    },
+
+    /// Generate an Atom or RSS feed of holidays and events, one entry per day.
+    Feed {
+        /// The Parsi year to cover (e.g., 1404). Defaults to the next 365 days from today.
+        #[arg(short, long)]
+        year: Option<i32>,
+
+        /// Cover the next N days from today instead of 365.
+        #[arg(short, long, value_name = "N", conflicts_with = "year")]
+        days: Option<u32>,
+
+        /// The feed format.
+        #[arg(short, long, value_enum, default_value_t = FeedFormat::Atom)]
+        format: FeedFormat,
+    },
+
+    /// List notable historical occasions that happened on a Parsi month/day.
+    #[command(name = "onthisday")]
+    OnThisDay {
+        /// The date to look up (e.g., YYYY/MM/DD). Defaults to today; only month and day are used.
+        date_string: Option<String>,
+
+        /// Only show occasions in this category (e.g., history, culture, science, sport, disaster).
+        #[arg(short, long)]
+        category: Option<String>,
+    },
+
+    /// List events for one or more Parsi dates, grouped by date.
     Events {
-        /// The date to check for events (e.g., YYYY/MM/DD, YYYY-MM-DD).
-        date_string: String,
+        /// The dates to check for events (e.g., YYYY/MM/DD, YYYY-MM-DD).
+        #[arg(required = true)]
+        date_strings: Vec<String>,
+    },
+
+    /// List recurring Iranian fiscal deadlines (payroll tax, insurance, VAT, tax returns) for a year.
+    FiscalDeadlines {
+        /// The Parsi year whose deadlines to list. Defaults to the current year.
+        year: Option<i32>,
+
+        /// Only list deadlines due from today through the next N days (for reminders).
+        #[arg(short, long, value_name = "DAYS", conflicts_with = "year")]
+        upcoming: Option<u32>,
+
+        /// Load deadline rules from a JSON file instead of the built-in ruleset.
+        #[arg(long, value_name = "FILE")]
+        rules: Option<std::path::PathBuf>,
+
+        /// Emit the deadlines as a JSON array.
+        #[arg(long)]
+        json: bool,
+    },
+
+    /// Show the astronomical moment of Nowruz (Sāl Tahvil) in Tehran time, with a countdown.
+    Nowruz {
+        /// The Parsi year whose Nowruz to show. Defaults to the next Sāl Tahvil.
+        year: Option<i32>,
     },
 }
 
@@ -194,3 +652,30 @@ pub enum FormatStyle {
     Long,  // D Month YYYY (e.g., 2 مرداد 1403)
     Iso,   // YYYY-MM-DD or YYYY-MM-DDTHH:MM:SS
 }
+
+// Enum for output formats supported by the `export` command
+#[derive(ValueEnum, Clone, Copy, Debug)]
+pub enum ExportFormat {
+    Csv,       // One row per day, RFC 4180 quoting
+    OrgDiary,  // One Org heading per event with an active timestamp
+    OrgAgenda, // One Org TODO heading per event, scheduled on its day
+    Sql,       // CREATE TABLE + INSERT statements for a date-dimension table
+    Parquet,   // Date-dimension dataset (requires the `arrow` feature)
+    Xlsx,      // Spreadsheet with one sheet per month, holidays highlighted
+}
+
+// Enum for feed formats supported by the `feed` command
+#[derive(ValueEnum, Clone, Copy, Debug)]
+pub enum FeedFormat {
+    Atom, // Atom 1.0
+    Rss,  // RSS 2.0
+}
+
+// Enum for operations supported by the `batch` command
+#[derive(ValueEnum, Clone, Copy, Debug)]
+pub enum BatchOperation {
+    ToGregorian,   // Parsi date/datetime -> Gregorian
+    FromGregorian, // Gregorian date/datetime -> Parsi
+    Weekday,       // Parsi date -> Persian weekday name
+    Info,          // Parsi date -> one `info --json` object per line (JSON Lines)
+}
@@ -0,0 +1,59 @@
+//  ~/src/clock.rs
+//
+//  * Copyright (C) 2024–2025 Parsicore <parsicore.dev@gmail.com>
+//  * Package : mitra
+//  * License : Apache-2.0
+//  * Version : 2.3.0
+//  * URL     : https://github.com/parsicore/Mitra
+//  * Sign: mitra-20250419-bd5fbe728fa2-5836b45f25d83501625cc5529193d5f0
+//
+//! The current date and time. Every "today"-dependent command reads the clock through
+//! this module, so setting `MITRA_NOW` (an RFC 3339 timestamp such as
+//! `2025-03-21T10:00:00+03:30`, or a plain `YYYY-MM-DD` date for midnight UTC) pins it
+//! to a fixed instant for reproducible output and tests.
+
+use crate::utils::map_mitra_error;
+use anyhow::{Context, Result};
+use chrono::{DateTime, FixedOffset, Local, NaiveDate, Utc};
+use parsidate::{ParsiDate, ParsiDateTime};
+
+/// Environment variable that overrides the system clock.
+const NOW_ENV: &str = "MITRA_NOW";
+
+/// The current instant: `MITRA_NOW` if set, otherwise the system clock in local time.
+fn current() -> Result<DateTime<FixedOffset>> {
+    let Ok(value) = std::env::var(NOW_ENV) else {
+        return Ok(Local::now().fixed_offset());
+    };
+    DateTime::parse_from_rfc3339(value.trim())
+        .or_else(|_| {
+            NaiveDate::parse_from_str(value.trim(), "%Y-%m-%d").map(|date| {
+                date.and_hms_opt(0, 0, 0)
+                    .expect("midnight is valid")
+                    .and_utc()
+                    .fixed_offset()
+            })
+        })
+        .with_context(|| {
+            format!(
+                "Error: {} must be an RFC 3339 timestamp (e.g., 2025-03-21T10:00:00+03:30) or a YYYY-MM-DD date, got '{}'.",
+                NOW_ENV, value
+            )
+        })
+}
+
+/// The current Parsi date and time (wall-clock time at the instant's offset).
+pub fn now() -> Result<ParsiDateTime> {
+    ParsiDateTime::from_gregorian(current()?.naive_local())
+        .map_err(|e| map_mitra_error(e, "converting the current time"))
+}
+
+/// Today's Parsi date.
+pub fn today() -> Result<ParsiDate> {
+    Ok(now()?.date())
+}
+
+/// The current instant in UTC, for machine-readable timestamps.
+pub fn utc_now() -> Result<DateTime<Utc>> {
+    Ok(current()?.with_timezone(&Utc))
+}
@@ -0,0 +1,153 @@
+//  ~/src/dataset_schema.rs
+//
+//  * Copyright (C) 2024–2025 Parsicore <parsicore.dev@gmail.com>
+//  * Package : mitra
+//  * License : Apache-2.0
+//  * Version : 2.3.0
+//  * URL     : https://github.com/parsicore/Mitra
+//  * Sign: mitra-20250419-bd5fbe728fa2-5836b45f25d83501625cc5529193d5f0
+//
+//! The schema of the embedded datasets (`src/data/events.json`, `src/data/onthisday.json`)
+//! and the checks applied to them, documented in CONTRIBUTING.md ("Editing Event Data").
+//!
+//! `build.rs` includes this file with `#[path]` to validate the data at compile time, and
+//! `mitra doctor` runs the same checks at runtime, so the two always agree. Only `serde` and
+//! `parsidate` may be used here, since those are the build script's only dependencies.
+//! Unknown fields are rejected so that misspelled keys are caught too.
+
+use parsidate::{MAX_PARSI_DATE, MIN_PARSI_DATE, ParsiDate};
+use serde::Deserialize;
+
+/// The top level of `events.json`.
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct EventsFile {
+    persian_reference_year: i32,
+    #[serde(rename = "Persian Calendar")]
+    persian_events: Vec<EventRecord>,
+    hijri_events_mapping: Vec<EventRecord>,
+}
+
+/// One entry of either event list in `events.json`.
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct EventRecord {
+    #[allow(dead_code)] // Only its presence and type are checked.
+    holiday: bool,
+    month: u32,
+    day: u32,
+    #[serde(rename = "type")]
+    event_type: String,
+    title: String,
+    hijri_month: Option<u32>,
+    hijri_day: Option<u32>,
+}
+
+/// The top level of `onthisday.json`.
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct OccasionsFile {
+    occasions: Vec<OccasionRecord>,
+}
+
+/// One historical occasion in `onthisday.json`.
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct OccasionRecord {
+    month: u32,
+    day: u32,
+    year: i32,
+    category: String,
+    title: String,
+}
+
+impl EventsFile {
+    /// Number of events in both lists.
+    pub fn entries(&self) -> usize {
+        self.persian_events.len() + self.hijri_events_mapping.len()
+    }
+
+    /// Checks every entry, returning one line per problem, each starting with its JSON path.
+    pub fn problems(&self) -> Vec<String> {
+        let mut problems = Vec::new();
+        let year = self.persian_reference_year;
+        let year_supported = (MIN_PARSI_DATE.year()..=MAX_PARSI_DATE.year()).contains(&year);
+        if !year_supported {
+            problems.push(format!(
+                "persian_reference_year: {} must be {}-{}",
+                year,
+                MIN_PARSI_DATE.year(),
+                MAX_PARSI_DATE.year()
+            ));
+        }
+
+        for (index, event) in self.persian_events.iter().enumerate() {
+            let location = format!("\"Persian Calendar\"[{}]", index);
+            event.check(&location, &mut problems);
+        }
+        for (index, event) in self.hijri_events_mapping.iter().enumerate() {
+            let location = format!("hijri_events_mapping[{}]", index);
+            event.check(&location, &mut problems);
+            if year_supported && ParsiDate::new(year, event.month, event.day).is_err() {
+                problems.push(format!(
+                    "{}: {}/{} does not exist in reference year {}",
+                    location, event.month, event.day, year
+                ));
+            }
+        }
+        problems
+    }
+}
+
+impl EventRecord {
+    fn check(&self, location: &str, problems: &mut Vec<String>) {
+        // Month must be 1-12 and day must fit the month's longest length (Esfand counts as 30).
+        let max_day = if self.month <= 6 { 31 } else { 30 };
+        if !(1..=12).contains(&self.month) || !(1..=max_day).contains(&self.day) {
+            problems.push(format!(
+                "{}: {}/{} is not a valid Shamsi month/day",
+                location, self.month, self.day
+            ));
+        }
+        if self.event_type.trim().is_empty() {
+            problems.push(format!("{}: empty \"type\"", location));
+        }
+        if self.title.trim().is_empty() {
+            problems.push(format!("{}: empty \"title\"", location));
+        }
+        if self.hijri_month.is_some_and(|m| !(1..=12).contains(&m)) {
+            problems.push(format!("{}: \"hijri_month\" must be 1-12", location));
+        }
+        if self.hijri_day.is_some_and(|d| !(1..=30).contains(&d)) {
+            problems.push(format!("{}: \"hijri_day\" must be 1-30", location));
+        }
+    }
+}
+
+impl OccasionsFile {
+    /// Number of occasions.
+    pub fn entries(&self) -> usize {
+        self.occasions.len()
+    }
+
+    /// Checks every occasion, returning one line per problem, each starting with its JSON path.
+    pub fn problems(&self) -> Vec<String> {
+        let mut problems = Vec::new();
+        for (index, occasion) in self.occasions.iter().enumerate() {
+            let location = format!("occasions[{}]", index);
+            if ParsiDate::new(occasion.year, occasion.month, occasion.day).is_err() {
+                problems.push(format!(
+                    "{}: {}/{}/{} is not a valid Parsi date",
+                    location, occasion.year, occasion.month, occasion.day
+                ));
+            }
+            if occasion.category.trim().is_empty() {
+                problems.push(format!("{}: empty \"category\"", location));
+            }
+            if occasion.title.trim().is_empty() {
+                problems.push(format!("{}: empty \"title\"", location));
+            }
+        }
+        problems
+    }
+}
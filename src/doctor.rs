@@ -0,0 +1,187 @@
+//  ~/src/doctor.rs
+//
+//  * Copyright (C) 2024–2025 Parsicore <parsicore.dev@gmail.com>
+//  * Package : mitra
+//  * License : Apache-2.0
+//  * Version : 2.3.0
+//  * URL     : https://github.com/parsicore/Mitra
+//  * Sign: mitra-20250419-bd5fbe728fa2-5836b45f25d83501625cc5529193d5f0
+//
+//! Internal consistency checks behind `mitra doctor`: validation of the embedded
+//! datasets and round-trip conversion spot checks across the supported range.
+
+use crate::events::{self, DatasetReport};
+use crate::utils::supported_range;
+use parsidate::ParsiDate;
+
+/// Spacing between the years sampled by the round-trip check. Chosen coprime
+/// with the 33-year leap cycle so the samples land on every cycle position.
+const ROUND_TRIP_YEAR_STEP: usize = 97;
+
+/// Result of a single check.
+#[derive(Debug, PartialEq, Eq)]
+pub enum Status {
+    Ok,
+    Fail,
+    /// The check does not apply to this build or installation.
+    Skip,
+}
+
+impl Status {
+    /// The tag printed in front of the check name.
+    pub fn label(&self) -> &'static str {
+        match self {
+            Status::Ok => "ok",
+            Status::Fail => "FAIL",
+            Status::Skip => "skip",
+        }
+    }
+}
+
+/// One line of the doctor report, with optional detail lines underneath.
+#[derive(Debug)]
+pub struct Check {
+    pub name: &'static str,
+    pub status: Status,
+    pub summary: String,
+    /// Individual problems or hints on how to fix them.
+    pub details: Vec<String>,
+}
+
+/// Runs every check in report order.
+pub fn run_checks() -> Vec<Check> {
+    vec![
+        dataset_check("events.json", events::validate_event_data()),
+        dataset_check("onthisday.json", events::validate_occasion_data()),
+        round_trip_check(),
+        Check {
+            name: "config",
+            status: Status::Skip,
+            summary: "mitra does not read a configuration file".to_string(),
+            details: Vec::new(),
+        },
+        Check {
+            name: "store",
+            status: Status::Skip,
+            summary: "mitra keeps no on-disk store; all data is compiled into the binary"
+                .to_string(),
+            details: Vec::new(),
+        },
+    ]
+}
+
+fn dataset_check(name: &'static str, report: DatasetReport) -> Check {
+    match report {
+        DatasetReport::NotEmbedded => Check {
+            name,
+            status: Status::Skip,
+            summary: "not embedded in this build".to_string(),
+            details: vec![
+                "rebuild with the `embedded-events` feature to include event data".to_string(),
+            ],
+        },
+        DatasetReport::Checked { entries, problems } if problems.is_empty() => Check {
+            name,
+            status: Status::Ok,
+            summary: format!("{} entries valid", entries),
+            details: Vec::new(),
+        },
+        DatasetReport::Checked { entries, problems } => {
+            let summary = format!("{} problem(s) in {} entries", problems.len(), entries);
+            let mut details = problems;
+            details.push(format!(
+                "fix src/data/{} and rebuild; event lookups may be incomplete until then",
+                name
+            ));
+            Check {
+                name,
+                status: Status::Fail,
+                summary,
+                details,
+            }
+        }
+    }
+}
+
+/// Converts the first and last day of every month of the sampled years to
+/// Gregorian and back, and checks that each year's last day is followed by
+/// the next year's first day.
+fn round_trip_check() -> Check {
+    let range = supported_range();
+    let mut years: Vec<i32> = range.clone().step_by(ROUND_TRIP_YEAR_STEP).collect();
+    if years.last() != Some(range.end()) {
+        years.push(*range.end());
+    }
+
+    let mut checked = 0;
+    let mut problems = Vec::new();
+    for &year in &years {
+        for month in 1..=12 {
+            for day in [1, ParsiDate::days_in_month(year, month)] {
+                checked += 1;
+                if let Err(problem) = round_trip(year, month, day) {
+                    problems.push(problem);
+                }
+            }
+        }
+        if year < *range.end() {
+            let last = ParsiDate::new(year, 12, ParsiDate::days_in_month(year, 12));
+            let next = last.and_then(|d| d.add_days(1));
+            match next {
+                Ok(next) if (next.year(), next.month(), next.day()) == (year + 1, 1, 1) => {}
+                Ok(next) => problems.push(format!(
+                    "the day after the end of {} is {}, expected {:04}/01/01",
+                    year,
+                    next,
+                    year + 1
+                )),
+                Err(e) => problems.push(format!("crossing into {} failed: {}", year + 1, e)),
+            }
+        }
+    }
+
+    let summary = format!(
+        "{} dates in {} sampled years between {} and {}",
+        checked,
+        years.len(),
+        range.start(),
+        range.end()
+    );
+    if problems.is_empty() {
+        Check {
+            name: "round-trip",
+            status: Status::Ok,
+            summary,
+            details: Vec::new(),
+        }
+    } else {
+        problems.push(
+            "conversions are unreliable; please report this together with `mitra --version`"
+                .to_string(),
+        );
+        Check {
+            name: "round-trip",
+            status: Status::Fail,
+            summary,
+            details: problems,
+        }
+    }
+}
+
+/// Converts one Parsi date to Gregorian and back.
+fn round_trip(year: i32, month: u32, day: u32) -> Result<(), String> {
+    let date = ParsiDate::new(year, month, day)
+        .map_err(|e| format!("{:04}/{:02}/{:02} rejected: {}", year, month, day, e))?;
+    let gregorian = date
+        .to_gregorian()
+        .map_err(|e| format!("{} to Gregorian failed: {}", date, e))?;
+    let back = ParsiDate::from_gregorian(gregorian)
+        .map_err(|e| format!("{} back from Gregorian {} failed: {}", date, gregorian, e))?;
+    if back != date {
+        return Err(format!(
+            "{} -> {} -> {} does not round-trip",
+            date, gregorian, back
+        ));
+    }
+    Ok(())
+}
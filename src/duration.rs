@@ -0,0 +1,103 @@
+//  ~/src/duration.rs
+//
+//  * Copyright (C) 2024–2025 Parsicore <parsicore.dev@gmail.com>
+//  * Package : mitra
+//  * License : Apache-2.0
+//  * Version : 2.3.0
+//  * URL     : https://github.com/parsicore/Mitra
+//  * Sign: mitra-20250419-bd5fbe728fa2-5836b45f25d83501625cc5529193d5f0
+//
+//! Calendar-aware durations for the `diff` command: the span between two datetimes
+//! as years, months, days, hours, minutes, and seconds.
+//!
+//! Years and months are counted on the Parsi calendar with the same day clamping as
+//! `add --months`, so adding the breakdown to the earlier datetime (months first,
+//! then the rest) gives back the later one.
+
+use crate::utils::map_mitra_error;
+use anyhow::Result;
+use parsidate::ParsiDateTime;
+use std::fmt;
+
+/// The span between two datetimes, split into calendar components.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DurationBreakdown {
+    pub years: u32,
+    /// Months beyond the whole years (0-11).
+    pub months: u32,
+    /// Days beyond the whole months.
+    pub days: u32,
+    pub hours: u32,
+    pub minutes: u32,
+    pub seconds: u32,
+}
+
+/// Breaks the span between `pdt1` and `pdt2` into calendar components. The span is
+/// absolute: the result does not depend on which of the two is earlier.
+pub fn get_duration_breakdown(
+    pdt1: &ParsiDateTime,
+    pdt2: &ParsiDateTime,
+) -> Result<DurationBreakdown> {
+    let (start, end) = if pdt2 < pdt1 {
+        (pdt2, pdt1)
+    } else {
+        (pdt1, pdt2)
+    };
+
+    // Whole months from the start, backing off one if the day or time has not been reached.
+    let mut total_months =
+        (end.year() - start.year()) * 12 + end.month() as i32 - start.month() as i32;
+    let mut anchor = add_months(start, total_months)?;
+    if anchor > *end {
+        total_months -= 1;
+        anchor = add_months(start, total_months)?;
+    }
+
+    let rest = end
+        .to_gregorian()
+        .map_err(|e| map_mitra_error(e, "converting to Gregorian"))?
+        - anchor
+            .to_gregorian()
+            .map_err(|e| map_mitra_error(e, "converting to Gregorian"))?;
+    let rest_seconds = rest.num_seconds();
+
+    Ok(DurationBreakdown {
+        years: (total_months / 12) as u32,
+        months: (total_months % 12) as u32,
+        days: (rest_seconds / 86_400) as u32,
+        hours: (rest_seconds % 86_400 / 3_600) as u32,
+        minutes: (rest_seconds % 3_600 / 60) as u32,
+        seconds: (rest_seconds % 60) as u32,
+    })
+}
+
+fn add_months(pdt: &ParsiDateTime, months: i32) -> Result<ParsiDateTime> {
+    pdt.add_months(months)
+        .map_err(|e| map_mitra_error(e, "adding months"))
+}
+
+impl fmt::Display for DurationBreakdown {
+    /// Lists the non-zero components, e.g. "1 year, 2 months, 5 hours"; an empty span is "0 seconds".
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let components = [
+            (self.years, "year"),
+            (self.months, "month"),
+            (self.days, "day"),
+            (self.hours, "hour"),
+            (self.minutes, "minute"),
+            (self.seconds, "second"),
+        ];
+        let parts: Vec<String> = components
+            .iter()
+            .filter(|(value, _)| *value > 0)
+            .map(|(value, unit)| {
+                format!("{} {}{}", value, unit, if *value == 1 { "" } else { "s" })
+            })
+            .collect();
+        if parts.is_empty() {
+            write!(f, "0 seconds")
+        } else {
+            write!(f, "{}", parts.join(", "))
+        }
+    }
+}
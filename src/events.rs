@@ -8,16 +8,26 @@
 //  * Sign: mitra-20250419-bd5fbe728fa2-5836b45f25d83501625cc5529193d5f0
 //
 //! Handles loading, storing, and querying calendar event data.
-//! Reads event information from an embedded JSON file (`src/data/events.json`).
+//! Reads event information from an embedded JSON file (`src/data/events.json`);
+//! building without the `embedded-events` feature leaves both datasets empty.
 //! Supports fixed Persian calendar events and Hijri events mapped to a specific
-//! reference Persian year.
+//! reference Persian year, plus a separate read-only dataset of historical
+//! "on this day" occasions (`src/data/onthisday.json`). For other years, mapped
+//! events that carry their Hijri date are projected through the tabular calendar
+//! in `hijri`, so religious events and holidays are available for any year.
+//! Both datasets are checked against `dataset_schema` by `build.rs` at compile time.
 
+use crate::dataset_schema::{EventsFile, OccasionsFile};
+use crate::hijri::{self, HijriDate};
 use once_cell::sync::Lazy;
-use serde::Deserialize;
-use std::collections::HashMap; // Used to potentially get current year if needed, though not currently
+use parsidate::ParsiDate;
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+use std::sync::{Arc, Mutex};
 
 // Represents a single calendar event.
-#[derive(Deserialize, Debug, Clone)]
+#[derive(Deserialize, Serialize, Debug, Clone)]
 pub struct Event {
     /// Indicates if the event is an official public holiday.
     pub holiday: bool,
@@ -33,10 +43,11 @@ pub struct Event {
     /// The title or description of the event.
     pub title: String,
     /// The original Hijri month (1-12), if this event is a mapped Hijri event.
-    #[serde(default)] // Make optional as it only exists for mapped events
+    // Optional as it only exists for mapped events; omitted from JSON output when absent.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub hijri_month: Option<u32>,
     /// The original Hijri day (1-30), if this event is a mapped Hijri event.
-    #[serde(default)] // Make optional
+    #[serde(default, skip_serializing_if = "Option::is_none")] // Make optional
     pub hijri_day: Option<u32>,
 }
 
@@ -69,13 +80,41 @@ struct LoadedEvents {
     fixed_persian_events: EventMap,
     /// Map storing Hijri events mapped to Shamsi dates for the reference year [(Month, Day) -> Vec<Event>].
     mapped_hijri_events: EventMap,
+    /// Mapped events that carry a Hijri month/day, one per Hijri date, for other years.
+    lunar_events: Vec<Event>,
 }
 
+impl LoadedEvents {
+    /// An empty event set, used when no event data is available.
+    fn empty() -> Self {
+        LoadedEvents {
+            reference_year: 0, // Using 0 to indicate an error state
+            fixed_persian_events: HashMap::new(),
+            mapped_hijri_events: HashMap::new(),
+            lunar_events: Vec::new(),
+        }
+    }
+}
+
+// Embed the JSON file content directly into the binary at compile time,
+// unless the `embedded-events` feature is disabled to slim the binary.
+#[cfg(feature = "embedded-events")]
+const EVENTS_JSON: Option<&str> = Some(include_str!("data/events.json"));
+#[cfg(not(feature = "embedded-events"))]
+const EVENTS_JSON: Option<&str> = None;
+
+#[cfg(feature = "embedded-events")]
+const OCCASIONS_JSON: Option<&str> = Some(include_str!("data/onthisday.json"));
+#[cfg(not(feature = "embedded-events"))]
+const OCCASIONS_JSON: Option<&str> = None;
+
 // Lazily load and process the event data from the embedded JSON file.
 // Ensures the JSON is parsed only once during the application's lifetime.
 static LOADED_DATA: Lazy<LoadedEvents> = Lazy::new(|| {
-    // Embed the JSON file content directly into the binary at compile time.
-    let json_data = include_str!("data/events.json");
+    let Some(json_data) = EVENTS_JSON else {
+        log::debug!("Built without the `embedded-events` feature; no event data is available");
+        return LoadedEvents::empty();
+    };
 
     // Attempt to parse the JSON data into our CalendarData struct.
     match serde_json::from_str::<CalendarData>(json_data) {
@@ -94,6 +133,21 @@ static LOADED_DATA: Lazy<LoadedEvents> = Lazy::new(|| {
                     .push(clean_event); // Add event to the vec
             }
 
+            // Keep one event per Hijri date for projecting onto other years. A lunar year is
+            // about 11 days shorter than a Shamsi one, so the reference year can list the
+            // same Hijri date twice (e.g. 21 Ramadan in both Farvardin and Esfand).
+            let mut lunar_events: Vec<Event> = Vec::new();
+            for event in &data.hijri_events_mapping {
+                if event.hijri_month.is_some()
+                    && event.hijri_day.is_some()
+                    && !lunar_events.iter().any(|e| {
+                        (e.hijri_month, e.hijri_day) == (event.hijri_month, event.hijri_day)
+                    })
+                {
+                    lunar_events.push(event.clone());
+                }
+            }
+
             // Process mapped Hijri events into their own map.
             let mut mapped_hijri_events: EventMap = HashMap::new();
             for event in data.hijri_events_mapping {
@@ -105,35 +159,110 @@ static LOADED_DATA: Lazy<LoadedEvents> = Lazy::new(|| {
                     .push(event);
             }
 
+            log::debug!(
+                "Loaded {} fixed Persian event dates, {} mapped Hijri event dates (reference year {}) and {} Hijri dates for other years",
+                fixed_persian_events.len(),
+                mapped_hijri_events.len(),
+                data.persian_reference_year,
+                lunar_events.len()
+            );
+
             // Return the processed data wrapped in LoadedEvents.
             LoadedEvents {
                 reference_year: data.persian_reference_year,
                 fixed_persian_events,
                 mapped_hijri_events,
+                lunar_events,
             }
         }
         Err(e) => {
             // If JSON parsing fails, log a critical error and return an empty structure.
             // Event functionality will be effectively disabled.
-            eprintln!(
-                "CRITICAL: Error parsing event data from 'events.json': {}",
-                e
-            );
-            eprintln!("Event listing and calendar indicators will be unavailable.");
-            LoadedEvents {
-                reference_year: 0, // Using 0 to indicate an error state
-                fixed_persian_events: HashMap::new(),
-                mapped_hijri_events: HashMap::new(),
-            }
+            log::error!("Error parsing event data from 'events.json': {}", e);
+            log::error!("Event listing and calendar indicators will be unavailable.");
+            LoadedEvents::empty()
         }
     }
 });
 
+// Hijri events placed on Shamsi dates, per Shamsi year, computed on first use.
+static HIJRI_CACHE: Lazy<Mutex<HashMap<i32, Arc<EventMap>>>> =
+    Lazy::new(|| Mutex::new(HashMap::new()));
+
+/// Places every mapped event that carries a Hijri date on the Shamsi dates it falls on in
+/// `year`, using the tabular Hijri calendar. A Hijri date can fall twice in one Shamsi
+/// year; an event on the 30th of a 29-day month is kept on the month's last day.
+fn project_hijri_events(year: i32) -> EventMap {
+    let mut events: EventMap = HashMap::new();
+    let Ok(first) = ParsiDate::new(year, 1, 1) else {
+        return events;
+    };
+    let hijri_year = |date: ParsiDate| {
+        date.to_gregorian()
+            .ok()
+            .and_then(|g| HijriDate::from_gregorian(g).ok())
+            .map(HijriDate::year)
+    };
+    // Years before the Hijri epoch (622 CE) start in no Hijri year at all.
+    let first_hijri_year = hijri_year(first).unwrap_or(1);
+    let Some(last_hijri_year) = hijri_year(first.last_day_of_year()) else {
+        return events;
+    };
+
+    for hijri_year in first_hijri_year..=last_hijri_year {
+        for event in &LOADED_DATA.lunar_events {
+            let (Some(month), Some(day)) = (event.hijri_month, event.hijri_day) else {
+                continue;
+            };
+            let day = day.min(hijri::days_in_month(hijri_year, month));
+            let Some(date) = HijriDate::new(hijri_year, month, day)
+                .and_then(HijriDate::to_gregorian)
+                .ok()
+                .and_then(|g| ParsiDate::from_gregorian(g).ok())
+            else {
+                continue;
+            };
+            if date.year() == year {
+                events
+                    .entry((date.month(), date.day()))
+                    .or_default()
+                    .push(Event {
+                        month: date.month(),
+                        day: date.day(),
+                        ..event.clone()
+                    });
+            }
+        }
+    }
+    log::trace!(
+        "Projected {} Hijri event dates onto year {}",
+        events.len(),
+        year
+    );
+    events
+}
+
+/// Returns the Hijri events of a Shamsi year keyed by (month, day): the published mapping
+/// for the reference year, and the tabular projection for every other year.
+fn hijri_events_for_year(year: i32) -> Arc<EventMap> {
+    // A poisoned lock only means another thread panicked mid-insert; the map is still usable.
+    let mut cache = HIJRI_CACHE.lock().unwrap_or_else(|e| e.into_inner());
+    Arc::clone(cache.entry(year).or_insert_with(|| {
+        if year == LOADED_DATA.reference_year {
+            Arc::new(LOADED_DATA.mapped_hijri_events.clone())
+        } else {
+            Arc::new(project_hijri_events(year))
+        }
+    }))
+}
+
 /// Returns a combined list of relevant `Event`s for the given Shamsi year, month, and day.
 ///
 /// This function always includes fixed Persian events (those occurring on the same
-/// Shamsi date each year). It *only* includes mapped Hijri events if the `query_year`
-/// matches the `reference_year` defined in the loaded event data.
+/// Shamsi date each year), followed by the Hijri events of `query_year`: the mapped
+/// dates in the reference year, and dates projected through the tabular Hijri
+/// calendar (which can differ from the official, sighting-based dates by a day) in
+/// any other year.
 ///
 /// Returns `None` if no relevant events are found for the given date and year context,
 /// or if the event data failed to load initially.
@@ -147,6 +276,7 @@ pub fn get_events_for_date(
 
     // If the reference year is 0, it indicates the data failed to load.
     if loaded_data.reference_year == 0 {
+        log::debug!("Event data unavailable; skipping lookup");
         return None;
     }
 
@@ -161,13 +291,9 @@ pub fn get_events_for_date(
         results.extend(fixed_events.iter().cloned());
     }
 
-    // 2. Conditionally add mapped Hijri events: Only if the queried year
-    //    matches the year for which the mapping is valid.
-    if query_year == loaded_data.reference_year {
-        if let Some(mapped_events) = loaded_data.mapped_hijri_events.get(&key) {
-            // Extend the results with clones of the mapped events.
-            results.extend(mapped_events.iter().cloned());
-        }
+    // 2. Add the Hijri events that fall on this date in the queried year.
+    if let Some(hijri_events) = hijri_events_for_year(query_year).get(&key) {
+        results.extend(hijri_events.iter().cloned());
     }
 
     // Return the combined list if it's not empty, otherwise return None.
@@ -182,8 +308,7 @@ pub fn get_events_for_date(
 
 /// Determines an indicator character for calendar display based on events for a specific date.
 ///
-/// Considers both fixed Persian events and mapped Hijri events (only if the `query_year`
-/// matches the reference year).
+/// Considers both fixed Persian events and the Hijri events of `query_year`.
 ///
 /// Returns:
 /// - `Some('*')`: If there is at least one holiday event for the date in the relevant year context.
@@ -202,3 +327,204 @@ pub fn get_event_indicator(query_year: i32, query_month: u32, query_day: u32) ->
         // If get_events_for_date returned None, this .map() is skipped, returning None.
     })
 }
+
+// The set of (Month, Day) pairs that are official holidays in one Shamsi year.
+type YearHolidays = HashSet<(u32, u32)>;
+
+// Per-year holiday sets, computed on first use and kept for the rest of the run,
+// so repeated `is_holiday` checks don't re-merge the fixed and Hijri events.
+static HOLIDAY_CACHE: Lazy<Mutex<HashMap<i32, Arc<YearHolidays>>>> =
+    Lazy::new(|| Mutex::new(HashMap::new()));
+
+/// Collects the holiday (month, day) pairs for a Shamsi year, from the fixed events
+/// and the same Hijri events as `get_events_for_date`.
+fn compute_year_holidays(year: i32) -> YearHolidays {
+    let loaded_data = &*LOADED_DATA;
+    let mut holidays: YearHolidays = loaded_data
+        .fixed_persian_events
+        .iter()
+        .filter(|(_, events)| events.iter().any(|e| e.holiday))
+        .map(|(key, _)| *key)
+        .collect();
+    holidays.extend(
+        hijri_events_for_year(year)
+            .iter()
+            .filter(|(_, events)| events.iter().any(|e| e.holiday))
+            .map(|(key, _)| *key),
+    );
+    log::trace!("Computed {} holidays for year {}", holidays.len(), year);
+    holidays
+}
+
+/// Returns `true` if the given date is an official public holiday.
+///
+/// Holidays are computed once per year and memoized, so this is cheap to call
+/// for every day of a month or year.
+pub fn is_holiday(date: &ParsiDate) -> bool {
+    let holidays = {
+        // A poisoned lock only means another thread panicked mid-insert; the map is still usable.
+        let mut cache = HOLIDAY_CACHE.lock().unwrap_or_else(|e| e.into_inner());
+        Arc::clone(
+            cache
+                .entry(date.year())
+                .or_insert_with(|| Arc::new(compute_year_holidays(date.year()))),
+        )
+    };
+    holidays.contains(&(date.month(), date.day()))
+}
+
+/// How far ahead callers of `next_events_from` look for the next event: a bit over one
+/// year, so every yearly event is reached.
+pub const NEXT_EVENT_WINDOW_DAYS: u32 = 370;
+
+/// Finds the first date on or after `from` (looking at most `max_days` ahead) that has events,
+/// returning that date and its events.
+///
+/// Returns `None` if no events are found within the window or the date range is exhausted.
+pub fn next_events_from(from: &ParsiDate, max_days: u32) -> Option<(ParsiDate, Vec<Event>)> {
+    let mut date = *from;
+    for _ in 0..=max_days {
+        if let Some(events) = get_events_for_date(date.year(), date.month(), date.day()) {
+            return Some((date, events));
+        }
+        date = date.add_days(1).ok()?;
+    }
+    None
+}
+
+// --- On-This-Day Historical Occasions ---
+
+/// A notable historical event that happened on a given Shamsi month/day.
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct Occasion {
+    /// The Shamsi month (1-12) the occasion happened on.
+    pub month: u32,
+    /// The Shamsi day (1-31) the occasion happened on.
+    pub day: u32,
+    /// The Shamsi year the occasion happened in.
+    pub year: i32,
+    /// A short category used for filtering (e.g., "history", "culture", "science").
+    pub category: String,
+    /// The title or description of the occasion.
+    pub title: String,
+}
+
+// Structure mirroring the top-level JSON data file (`onthisday.json`).
+#[derive(Deserialize, Debug)]
+struct OccasionData {
+    #[serde(default)]
+    occasions: Vec<Occasion>,
+}
+
+// Lazily load the read-only on-this-day dataset, keyed by (Month, Day).
+static LOADED_OCCASIONS: Lazy<HashMap<(u32, u32), Vec<Occasion>>> = Lazy::new(|| {
+    let Some(json_data) = OCCASIONS_JSON else {
+        log::debug!("Built without the `embedded-events` feature; no occasion data is available");
+        return HashMap::new();
+    };
+
+    match serde_json::from_str::<OccasionData>(json_data) {
+        Ok(data) => {
+            let mut occasions: HashMap<(u32, u32), Vec<Occasion>> = HashMap::new();
+            for occasion in data.occasions {
+                occasions
+                    .entry((occasion.month, occasion.day))
+                    .or_default()
+                    .push(occasion);
+            }
+            // Keep each day's list in chronological order.
+            for list in occasions.values_mut() {
+                list.sort_by_key(|o| o.year);
+            }
+            occasions
+        }
+        Err(e) => {
+            log::error!("Error parsing occasion data from 'onthisday.json': {}", e);
+            log::error!("On-this-day listing will be unavailable.");
+            HashMap::new()
+        }
+    }
+});
+
+/// Returns the historical occasions recorded for the given Shamsi month and day,
+/// oldest first, optionally restricted to a single category (case-insensitive).
+///
+/// Returns an empty list if nothing matches or if the dataset failed to load.
+pub fn get_occasions_for_date(
+    query_month: u32,
+    query_day: u32,
+    category: Option<&str>,
+) -> Vec<Occasion> {
+    LOADED_OCCASIONS
+        .get(&(query_month, query_day))
+        .map(|list| {
+            list.iter()
+                .filter(|o| category.is_none_or(|c| o.category.eq_ignore_ascii_case(c)))
+                .cloned()
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+/// Outcome of validating one embedded dataset with [`validate_event_data`] or
+/// [`validate_occasion_data`].
+#[derive(Debug)]
+pub enum DatasetReport {
+    /// The binary was built without the `embedded-events` feature.
+    NotEmbedded,
+    /// The dataset was parsed and checked entry by entry.
+    Checked {
+        /// Number of entries examined.
+        entries: usize,
+        /// One human-readable line per problem found; empty when the data is valid.
+        problems: Vec<String>,
+    },
+}
+
+/// Parses an embedded dataset with its `dataset_schema` type and runs the same checks as
+/// `build.rs`, independently of the lenient, lazily loaded copy used for lookups.
+fn validate_dataset<T: DeserializeOwned>(
+    name: &str,
+    json: Option<&str>,
+    entries: impl Fn(&T) -> usize,
+    problems: impl Fn(&T) -> Vec<String>,
+) -> DatasetReport {
+    let Some(json_data) = json else {
+        return DatasetReport::NotEmbedded;
+    };
+    match serde_json::from_str::<T>(json_data) {
+        Ok(data) => DatasetReport::Checked {
+            entries: entries(&data),
+            problems: problems(&data),
+        },
+        Err(e) => DatasetReport::Checked {
+            entries: 0,
+            problems: vec![format!(
+                "{} does not match the expected schema: {}",
+                name, e
+            )],
+        },
+    }
+}
+
+/// Validates the embedded `events.json`, so parse errors are reported instead of
+/// silently yielding an empty event set.
+pub fn validate_event_data() -> DatasetReport {
+    validate_dataset(
+        "events.json",
+        EVENTS_JSON,
+        EventsFile::entries,
+        EventsFile::problems,
+    )
+}
+
+/// Validates the embedded `onthisday.json`: every occasion must fall on a real
+/// date of its year and carry a category and a title.
+pub fn validate_occasion_data() -> DatasetReport {
+    validate_dataset(
+        "onthisday.json",
+        OCCASIONS_JSON,
+        OccasionsFile::entries,
+        OccasionsFile::problems,
+    )
+}
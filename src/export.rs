@@ -0,0 +1,345 @@
+//  ~/src/export.rs
+//
+//  * Copyright (C) 2024–2025 Parsicore <parsicore.dev@gmail.com>
+//  * Package : mitra
+//  * License : Apache-2.0
+//  * Version : 2.3.0
+//  * URL     : https://github.com/parsicore/Mitra
+//  * Sign: mitra-20250419-bd5fbe728fa2-5836b45f25d83501625cc5529193d5f0
+//
+//! Builds per-day calendar records for a Parsi year and renders them in
+//! export formats (CSV, Org-mode, SQL, Parquet, and XLSX) for use in spreadsheets and databases.
+
+use crate::events::{self, Event};
+use crate::utils::map_mitra_error;
+use crate::weekday::{WeekExt, WeekStart};
+use anyhow::{Context, Result};
+use chrono::NaiveDate;
+use parsidate::ParsiDate;
+use std::io::Write;
+
+/// One row of exported calendar data: everything known about a single day.
+#[derive(Debug, Clone)]
+pub struct DayRecord {
+    /// The Parsi date in `YYYY/MM/DD` form.
+    pub parsi_date: String,
+    pub parsi_year: i32,
+    pub parsi_month: u32,
+    pub parsi_day: u32,
+    /// The Gregorian date in `YYYY-MM-DD` form.
+    pub gregorian_date: String,
+    /// The Persian weekday name.
+    pub weekday: String,
+    /// The week of the Parsi year (weeks start on Saturday).
+    pub week_of_year: u32,
+    /// Whether the day is an official public holiday.
+    pub is_holiday: bool,
+    /// All events recorded for the day.
+    pub events: Vec<Event>,
+}
+
+/// Builds one `DayRecord` for every day of the given Parsi year, in order.
+pub fn year_records(year: i32) -> Result<Vec<DayRecord>> {
+    let first = ParsiDate::new(year, 1, 1)
+        .map_err(|e| map_mitra_error(e, &format!("creating date {}-1-1", year)))?;
+    range_records(&first, &first.last_day_of_year())
+}
+
+/// Builds one `DayRecord` for every day from `first` to `last` (inclusive), in order.
+pub fn range_records(first: &ParsiDate, last: &ParsiDate) -> Result<Vec<DayRecord>> {
+    let mut records = Vec::new();
+    let mut date = *first;
+    while date <= *last {
+        records.push(day_record(&date)?);
+        date = date
+            .add_days(1)
+            .map_err(|e| map_mitra_error(e, "iterating over dates"))?;
+    }
+    Ok(records)
+}
+
+/// Builds the `DayRecord` for a single date.
+fn day_record(date: &ParsiDate) -> Result<DayRecord> {
+    let gregorian = date
+        .to_gregorian()
+        .map_err(|e| map_mitra_error(e, "converting to Gregorian"))?;
+    let weekday = date
+        .weekday()
+        .map_err(|e| map_mitra_error(e, "getting weekday"))?;
+    let week_of_year = date.week_of_year_from(WeekStart::Sat)?;
+    let day_events =
+        events::get_events_for_date(date.year(), date.month(), date.day()).unwrap_or_default();
+
+    Ok(DayRecord {
+        parsi_date: date.to_string(),
+        parsi_year: date.year(),
+        parsi_month: date.month(),
+        parsi_day: date.day(),
+        gregorian_date: gregorian.format("%Y-%m-%d").to_string(),
+        weekday,
+        week_of_year,
+        is_holiday: day_events.iter().any(|e| e.holiday),
+        events: day_events,
+    })
+}
+
+/// Returns the titles of the record's events, in order.
+pub fn event_titles(record: &DayRecord) -> Vec<&str> {
+    record.events.iter().map(|e| e.title.as_str()).collect()
+}
+
+/// Quotes a CSV field if it contains a separator, quote, or line break (RFC 4180).
+fn csv_field(value: &str) -> String {
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+/// Writes the records as CSV with a header row. Multiple event titles are joined with "; ".
+pub fn write_csv<W: Write>(records: &[DayRecord], out: &mut W) -> Result<()> {
+    writeln!(
+        out,
+        "parsi_date,gregorian_date,weekday,week_of_year,is_holiday,events"
+    )?;
+    for record in records {
+        writeln!(
+            out,
+            "{},{},{},{},{},{}",
+            csv_field(&record.parsi_date),
+            csv_field(&record.gregorian_date),
+            csv_field(&record.weekday),
+            record.week_of_year,
+            record.is_holiday,
+            csv_field(&event_titles(record).join("; "))
+        )?;
+    }
+    Ok(())
+}
+
+/// Writes the records' events as Org-mode entries, one heading per event with an active
+/// timestamp so they show up in the Org agenda. With `agenda`, headings become TODO items
+/// scheduled on the event's day. Days without events are skipped.
+pub fn write_org<W: Write>(records: &[DayRecord], agenda: bool, out: &mut W) -> Result<()> {
+    writeln!(out, "#+TITLE: Persian calendar events")?;
+    writeln!(out, "#+FILETAGS: :mitra:")?;
+    for record in records {
+        let gregorian = NaiveDate::parse_from_str(&record.gregorian_date, "%Y-%m-%d")
+            .with_context(|| {
+                format!(
+                    "Invalid Gregorian date in record: {}",
+                    record.gregorian_date
+                )
+            })?;
+        // Org timestamps always use English weekday abbreviations.
+        let timestamp = gregorian.format("<%Y-%m-%d %a>");
+        for event in &record.events {
+            let keyword = if agenda { "TODO " } else { "" };
+            let tags = if event.holiday { " :holiday:" } else { "" };
+            writeln!(out)?;
+            writeln!(out, "* {}{}{}", keyword, event.title, tags)?;
+            // The property drawer must directly follow the heading (and its planning line).
+            if agenda {
+                writeln!(out, "SCHEDULED: {}", timestamp)?;
+            }
+            writeln!(out, ":PROPERTIES:")?;
+            writeln!(out, ":PARSI_DATE: {}", record.parsi_date)?;
+            writeln!(out, ":END:")?;
+            if !agenda {
+                writeln!(out, "{}", timestamp)?;
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Name of the table created by the SQL export.
+const SQL_TABLE: &str = "parsi_date_dim";
+
+/// Quotes a string as an SQL literal, doubling embedded single quotes.
+fn sql_string(value: &str) -> String {
+    format!("'{}'", value.replace('\'', "''"))
+}
+
+/// Writes the records as a portable SQL script: a CREATE TABLE for a date-dimension table
+/// followed by one INSERT per day, wrapped in a single transaction.
+/// Days without events get NULL in the `events` column.
+pub fn write_sql<W: Write>(records: &[DayRecord], out: &mut W) -> Result<()> {
+    writeln!(out, "CREATE TABLE IF NOT EXISTS {} (", SQL_TABLE)?;
+    writeln!(out, "    gregorian_date DATE PRIMARY KEY,")?;
+    writeln!(out, "    parsi_date CHAR(10) NOT NULL UNIQUE,")?;
+    writeln!(out, "    parsi_year INTEGER NOT NULL,")?;
+    writeln!(out, "    parsi_month INTEGER NOT NULL,")?;
+    writeln!(out, "    parsi_day INTEGER NOT NULL,")?;
+    writeln!(out, "    weekday VARCHAR(16) NOT NULL,")?;
+    writeln!(out, "    week_of_year INTEGER NOT NULL,")?;
+    writeln!(out, "    is_holiday BOOLEAN NOT NULL,")?;
+    writeln!(out, "    events TEXT")?;
+    writeln!(out, ");")?;
+    writeln!(out)?;
+    writeln!(out, "BEGIN;")?;
+    for record in records {
+        let events = if record.events.is_empty() {
+            "NULL".to_string()
+        } else {
+            sql_string(&event_titles(record).join("; "))
+        };
+        writeln!(
+            out,
+            "INSERT INTO {} VALUES ({}, {}, {}, {}, {}, {}, {}, {}, {});",
+            SQL_TABLE,
+            sql_string(&record.gregorian_date),
+            sql_string(&record.parsi_date),
+            record.parsi_year,
+            record.parsi_month,
+            record.parsi_day,
+            sql_string(&record.weekday),
+            record.week_of_year,
+            if record.is_holiday { "TRUE" } else { "FALSE" },
+            events
+        )?;
+    }
+    writeln!(out, "COMMIT;")?;
+    Ok(())
+}
+
+/// Writes the records as a Parquet file with the same columns as the SQL export.
+/// `gregorian_date` is stored as an Arrow `Date32`, and `events` is null for days without events.
+#[cfg(feature = "arrow")]
+pub fn write_parquet<W: Write + Send>(records: &[DayRecord], out: W) -> Result<()> {
+    use arrow_array::{ArrayRef, BooleanArray, Date32Array, Int32Array, RecordBatch, StringArray};
+    use arrow_schema::{DataType, Field, Schema};
+    use std::sync::Arc;
+
+    let epoch = NaiveDate::from_ymd_opt(1970, 1, 1).expect("epoch is a valid date");
+    let gregorian_days = records
+        .iter()
+        .map(|r| {
+            let date =
+                NaiveDate::parse_from_str(&r.gregorian_date, "%Y-%m-%d").with_context(|| {
+                    format!("Invalid Gregorian date in record: {}", r.gregorian_date)
+                })?;
+            i32::try_from((date - epoch).num_days()).context("Gregorian date out of Date32 range")
+        })
+        .collect::<Result<Vec<i32>>>()?;
+
+    let schema = Arc::new(Schema::new(vec![
+        Field::new("gregorian_date", DataType::Date32, false),
+        Field::new("parsi_date", DataType::Utf8, false),
+        Field::new("parsi_year", DataType::Int32, false),
+        Field::new("parsi_month", DataType::Int32, false),
+        Field::new("parsi_day", DataType::Int32, false),
+        Field::new("weekday", DataType::Utf8, false),
+        Field::new("week_of_year", DataType::Int32, false),
+        Field::new("is_holiday", DataType::Boolean, false),
+        Field::new("events", DataType::Utf8, true),
+    ]));
+    let columns: Vec<ArrayRef> = vec![
+        Arc::new(Date32Array::from(gregorian_days)),
+        Arc::new(StringArray::from_iter_values(
+            records.iter().map(|r| r.parsi_date.as_str()),
+        )),
+        Arc::new(Int32Array::from_iter_values(
+            records.iter().map(|r| r.parsi_year),
+        )),
+        Arc::new(Int32Array::from_iter_values(
+            records.iter().map(|r| r.parsi_month as i32),
+        )),
+        Arc::new(Int32Array::from_iter_values(
+            records.iter().map(|r| r.parsi_day as i32),
+        )),
+        Arc::new(StringArray::from_iter_values(
+            records.iter().map(|r| r.weekday.as_str()),
+        )),
+        Arc::new(Int32Array::from_iter_values(
+            records.iter().map(|r| r.week_of_year as i32),
+        )),
+        Arc::new(BooleanArray::from_iter(
+            records.iter().map(|r| Some(r.is_holiday)),
+        )),
+        Arc::new(StringArray::from_iter(records.iter().map(|r| {
+            (!r.events.is_empty()).then(|| event_titles(r).join("; "))
+        }))),
+    ];
+    let batch = RecordBatch::try_new(schema.clone(), columns)
+        .context("Failed to build Arrow record batch")?;
+
+    let mut writer = parquet::arrow::ArrowWriter::try_new(out, schema, None)
+        .context("Failed to create Parquet writer")?;
+    writer
+        .write(&batch)
+        .context("Failed to write Parquet data")?;
+    writer.close().context("Failed to finish Parquet file")?;
+    Ok(())
+}
+
+/// Renders the records as an XLSX workbook with one right-to-left sheet per month.
+/// Each sheet lists the month's days with their weekday, Gregorian date, and events;
+/// holiday rows are highlighted. Returns the workbook file contents.
+#[cfg(feature = "export")]
+pub fn xlsx_workbook(records: &[DayRecord]) -> Result<Vec<u8>> {
+    use rust_xlsxwriter::{Color, Format, FormatAlign, Workbook};
+
+    let title_format = Format::new().set_bold().set_font_size(14);
+    let header_format = Format::new()
+        .set_bold()
+        .set_background_color(Color::Theme(0, 2))
+        .set_align(FormatAlign::Center);
+    let day_format = Format::new().set_align(FormatAlign::Top).set_text_wrap();
+    let holiday_format = day_format
+        .clone()
+        .set_font_color(Color::RGB(0x9C0006))
+        .set_background_color(Color::RGB(0xFFC7CE));
+
+    let mut workbook = Workbook::new();
+    for month_records in
+        records.chunk_by(|a, b| (a.parsi_year, a.parsi_month) == (b.parsi_year, b.parsi_month))
+    {
+        let first = &month_records[0];
+        let month_name = ParsiDate::new(first.parsi_year, first.parsi_month, 1)
+            .map_err(|e| map_mitra_error(e, "creating month start date"))?
+            .format("%B");
+        let sheet_name = format!("{} {}", month_name, first.parsi_year);
+
+        let sheet = workbook.add_worksheet();
+        sheet.set_name(&sheet_name)?.set_right_to_left(true);
+        sheet.set_column_width(0, 12)?;
+        sheet.set_column_width(1, 10)?;
+        sheet.set_column_width(2, 12)?;
+        sheet.set_column_width(3, 8)?;
+        sheet.set_column_width(4, 80)?;
+
+        sheet.write_string_with_format(0, 0, &sheet_name, &title_format)?;
+        for (col, header) in ["تاریخ", "روز هفته", "میلادی", "تعطیل", "مناسبت‌ها"]
+            .into_iter()
+            .enumerate()
+        {
+            sheet.write_string_with_format(1, col as u16, header, &header_format)?;
+        }
+        sheet.set_freeze_panes(2, 0)?;
+
+        for (i, record) in month_records.iter().enumerate() {
+            let row = i as u32 + 2;
+            let format = if record.is_holiday {
+                &holiday_format
+            } else {
+                &day_format
+            };
+            sheet.write_string_with_format(row, 0, &record.parsi_date, format)?;
+            sheet.write_string_with_format(row, 1, &record.weekday, format)?;
+            sheet.write_string_with_format(row, 2, &record.gregorian_date, format)?;
+            sheet.write_string_with_format(
+                row,
+                3,
+                if record.is_holiday { "تعطیل" } else { "" },
+                format,
+            )?;
+            sheet.write_string_with_format(row, 4, event_titles(record).join("\n"), format)?;
+        }
+    }
+
+    workbook
+        .save_to_buffer()
+        .context("Failed to build XLSX workbook")
+}
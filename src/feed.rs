@@ -0,0 +1,160 @@
+//  ~/src/feed.rs
+//
+//  * Copyright (C) 2024–2025 Parsicore <parsicore.dev@gmail.com>
+//  * Package : mitra
+//  * License : Apache-2.0
+//  * Version : 2.3.0
+//  * URL     : https://github.com/parsicore/Mitra
+//  * Sign: mitra-20250419-bd5fbe728fa2-5836b45f25d83501625cc5529193d5f0
+//
+//! Renders holidays and events as Atom or RSS 2.0 feeds, one entry per day
+//! that has events, so they can be followed in feed readers.
+
+use crate::clock;
+use crate::export::{DayRecord, event_titles};
+use anyhow::{Context, Result};
+use chrono::{FixedOffset, NaiveDate, SecondsFormat, TimeZone};
+use std::io::Write;
+
+/// Iran Standard Time (UTC+03:30). Entries are dated at local midnight.
+const IRAN_OFFSET_SECONDS: i32 = 3 * 3600 + 30 * 60;
+
+/// Writes the days that have events as an Atom 1.0 feed.
+pub fn write_atom<W: Write>(title: &str, records: &[DayRecord], out: &mut W) -> Result<()> {
+    writeln!(out, r#"<?xml version="1.0" encoding="utf-8"?>"#)?;
+    writeln!(out, r#"<feed xmlns="http://www.w3.org/2005/Atom">"#)?;
+    writeln!(out, "  <title>{}</title>", escape_xml(title))?;
+    writeln!(out, "  <id>urn:mitra:feed:{}</id>", feed_slug(records))?;
+    writeln!(
+        out,
+        "  <updated>{}</updated>",
+        clock::utc_now()?.to_rfc3339_opts(SecondsFormat::Secs, true)
+    )?;
+    writeln!(out, "  <author><name>mitra</name></author>")?;
+    for record in records.iter().filter(|r| !r.events.is_empty()) {
+        let published = local_midnight(&record.gregorian_date)?;
+        writeln!(out, "  <entry>")?;
+        writeln!(
+            out,
+            "    <title>{}</title>",
+            escape_xml(&entry_title(record))
+        )?;
+        writeln!(out, "    <id>urn:mitra:day:{}</id>", record.gregorian_date)?;
+        writeln!(out, "    <updated>{}</updated>", published.to_rfc3339())?;
+        writeln!(out, "    <published>{}</published>", published.to_rfc3339())?;
+        if record.is_holiday {
+            writeln!(out, r#"    <category term="holiday"/>"#)?;
+        }
+        writeln!(
+            out,
+            r#"    <content type="text">{}</content>"#,
+            escape_xml(&entry_body(record))
+        )?;
+        writeln!(out, "  </entry>")?;
+    }
+    writeln!(out, "</feed>")?;
+    Ok(())
+}
+
+/// Writes the days that have events as an RSS 2.0 feed.
+pub fn write_rss<W: Write>(title: &str, records: &[DayRecord], out: &mut W) -> Result<()> {
+    writeln!(out, r#"<?xml version="1.0" encoding="utf-8"?>"#)?;
+    writeln!(out, r#"<rss version="2.0">"#)?;
+    writeln!(out, "  <channel>")?;
+    writeln!(out, "    <title>{}</title>", escape_xml(title))?;
+    writeln!(out, "    <link>https://github.com/parsicore/Mitra</link>")?;
+    writeln!(out, "    <description>{}</description>", escape_xml(title))?;
+    writeln!(
+        out,
+        "    <lastBuildDate>{}</lastBuildDate>",
+        clock::utc_now()?.to_rfc2822()
+    )?;
+    for record in records.iter().filter(|r| !r.events.is_empty()) {
+        let published = local_midnight(&record.gregorian_date)?;
+        writeln!(out, "    <item>")?;
+        writeln!(
+            out,
+            "      <title>{}</title>",
+            escape_xml(&entry_title(record))
+        )?;
+        writeln!(
+            out,
+            r#"      <guid isPermaLink="false">urn:mitra:day:{}</guid>"#,
+            record.gregorian_date
+        )?;
+        writeln!(out, "      <pubDate>{}</pubDate>", published.to_rfc2822())?;
+        if record.is_holiday {
+            writeln!(out, "      <category>holiday</category>")?;
+        }
+        writeln!(
+            out,
+            "      <description>{}</description>",
+            escape_xml(&entry_body(record))
+        )?;
+        writeln!(out, "    </item>")?;
+    }
+    writeln!(out, "  </channel>")?;
+    writeln!(out, "</rss>")?;
+    Ok(())
+}
+
+/// Entry title: the Parsi date followed by the first event title (holidays marked).
+fn entry_title(record: &DayRecord) -> String {
+    let first = record
+        .events
+        .first()
+        .map(|e| e.title.as_str())
+        .unwrap_or_default();
+    let marker = if record.is_holiday {
+        " [تعطیل]"
+    } else {
+        ""
+    };
+    format!("{}{} - {}", record.parsi_date, marker, first)
+}
+
+/// Entry body: the weekday, both calendar dates, and every event title on its own line.
+fn entry_body(record: &DayRecord) -> String {
+    format!(
+        "{} {} ({})\n{}",
+        record.weekday,
+        record.parsi_date,
+        record.gregorian_date,
+        event_titles(record).join("\n")
+    )
+}
+
+/// Identifies the feed by the Gregorian date range it covers.
+fn feed_slug(records: &[DayRecord]) -> String {
+    match (records.first(), records.last()) {
+        (Some(first), Some(last)) => format!("{}..{}", first.gregorian_date, last.gregorian_date),
+        _ => "empty".to_string(),
+    }
+}
+
+/// Parses a `YYYY-MM-DD` Gregorian date and returns midnight Iran Standard Time.
+fn local_midnight(gregorian_date: &str) -> Result<chrono::DateTime<FixedOffset>> {
+    let date = NaiveDate::parse_from_str(gregorian_date, "%Y-%m-%d")
+        .with_context(|| format!("Invalid Gregorian date in record: {}", gregorian_date))?;
+    let offset = FixedOffset::east_opt(IRAN_OFFSET_SECONDS).expect("offset is within range");
+    offset
+        .from_local_datetime(&date.and_hms_opt(0, 0, 0).expect("midnight is valid"))
+        .single()
+        .context("Ambiguous local time for feed entry")
+}
+
+/// Escapes the five XML special characters.
+fn escape_xml(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+    for c in value.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&apos;"),
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
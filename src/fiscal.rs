@@ -0,0 +1,188 @@
+//  ~/src/fiscal.rs
+//
+//  * Copyright (C) 2024–2025 Parsicore <parsicore.dev@gmail.com>
+//  * Package : mitra
+//  * License : Apache-2.0
+//  * Version : 2.3.0
+//  * URL     : https://github.com/parsicore/Mitra
+//  * Sign: mitra-20250419-bd5fbe728fa2-5836b45f25d83501625cc5529193d5f0
+//
+//! Recurring Iranian fiscal deadlines (payroll tax, social insurance, VAT, seasonal
+//! transaction reports, annual returns) computed from a ruleset. The built-in ruleset
+//! is `src/data/fiscal_rules.json`; `fiscal-deadlines --rules FILE` replaces it with a
+//! file of the same shape. Each rule is evaluated as a monthly `RecurrenceRule` on its due
+//! months, and a deadline that falls on a Friday or an official holiday moves to the
+//! next working day.
+
+use crate::business_days;
+use crate::recurrence::{self, RecurrenceRule};
+use crate::utils::map_mitra_error;
+use anyhow::{Context, Result, bail};
+use parsidate::ParsiDate;
+use serde::{Deserialize, Serialize};
+
+const BUILTIN_RULES: &str = include_str!("data/fiscal_rules.json");
+
+/// Persian season names, indexed by quarter (0 = spring).
+const SEASONS: [&str; 4] = ["بهار", "تابستان", "پاییز", "زمستان"];
+
+/// How often a deadline recurs: once per month, per quarter (season), or per year.
+#[derive(Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum Frequency {
+    Monthly,
+    Quarterly,
+    Yearly,
+}
+
+impl Frequency {
+    /// Length of one period in months.
+    fn months(self) -> u32 {
+        match self {
+            Frequency::Monthly => 1,
+            Frequency::Quarterly => 3,
+            Frequency::Yearly => 12,
+        }
+    }
+}
+
+/// A recurring deadline, due a fixed number of months after the end of each period.
+#[derive(Deserialize, Debug)]
+#[serde(deny_unknown_fields)]
+pub struct Rule {
+    pub title: String,
+    pub frequency: Frequency,
+    /// Months after the period's last month in which the deadline falls (0-12).
+    #[serde(default)]
+    pub offset_months: u32,
+    /// Day of that month (1-31, clamped to the month's length). Omitted means the last day.
+    #[serde(default)]
+    pub day: Option<u32>,
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct RuleSet {
+    rules: Vec<Rule>,
+}
+
+/// One computed deadline.
+#[derive(Serialize, Debug)]
+pub struct Deadline {
+    /// The effective due date, after moving past Fridays and holidays.
+    #[serde(skip)]
+    pub due: ParsiDate,
+    pub date: String,
+    pub weekday: String,
+    pub title: String,
+    /// The period the deadline settles (e.g., "زمستان 1403", "اسفند 1403", "سال 1403").
+    pub period: String,
+    /// The nominal due date, present only if it fell on a Friday or holiday.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub moved_from: Option<String>,
+}
+
+/// Returns the built-in ruleset.
+pub fn builtin_rules() -> Result<Vec<Rule>> {
+    parse_rules(BUILTIN_RULES).context("The built-in fiscal ruleset is invalid")
+}
+
+/// Parses and validates a ruleset document (`{"rules": [...]}`).
+pub fn parse_rules(json: &str) -> Result<Vec<Rule>> {
+    let set: RuleSet = serde_json::from_str(json).context("Invalid fiscal ruleset")?;
+    for (index, rule) in set.rules.iter().enumerate() {
+        if rule.title.trim().is_empty() {
+            bail!("Error: Fiscal rule {} has an empty title.", index);
+        }
+        if rule.offset_months > 12 {
+            bail!(
+                "Error: Fiscal rule \"{}\": offset_months must be 0-12.",
+                rule.title
+            );
+        }
+        if rule.day.is_some_and(|d| !(1..=31).contains(&d)) {
+            bail!("Error: Fiscal rule \"{}\": day must be 1-31.", rule.title);
+        }
+    }
+    Ok(set.rules)
+}
+
+impl Rule {
+    /// The nominal due dates as a recurrence rule: monthly, in each month `offset_months`
+    /// after a period ends, on `day` clamped to the month's length (the last of the days
+    /// 29..=`day` that the month has), or else on its last day.
+    fn recurrence(&self) -> RecurrenceRule {
+        let period_months = self.frequency.months();
+        let by_month = (1..=12)
+            .filter(|month| {
+                // The 0-based month that ends the period this month's deadline settles.
+                let period_end = (month + 23 - self.offset_months) % 12;
+                period_end % period_months == period_months - 1
+            })
+            .collect();
+        let by_month_day = match self.day {
+            Some(day) => (day.min(29) as i32..=day as i32).collect(),
+            None => vec![-1],
+        };
+        RecurrenceRule {
+            frequency: recurrence::Frequency::Monthly,
+            interval: 1,
+            by_month,
+            by_month_day,
+            by_day: Vec::new(),
+            by_set_pos: vec![-1],
+            count: None,
+            until: None,
+        }
+    }
+
+    /// The year and last month of the period settled by a deadline due on `due`.
+    fn period_of(&self, due: &ParsiDate) -> (i32, u32) {
+        let months = due.year() * 12 + due.month() as i32 - 1 - self.offset_months as i32;
+        (months.div_euclid(12), months.rem_euclid(12) as u32 + 1)
+    }
+}
+
+/// Computes every deadline whose effective due date falls within `first..=last`, in date order.
+pub fn deadlines_between(
+    rules: &[Rule],
+    first: &ParsiDate,
+    last: &ParsiDate,
+) -> Result<Vec<Deadline>> {
+    // Holiday shifts move a deadline at most a few days, so start a year early.
+    let from = ParsiDate::new((first.year() - 1).max(1), 1, 1)
+        .map_err(|e| map_mitra_error(e, "computing fiscal deadlines"))?;
+    let mut deadlines = Vec::new();
+    for rule in rules {
+        for nominal in rule.recurrence().occurrences(from, *last)? {
+            let due = business_days::next_business_day(&nominal)?;
+            if due < *first || due > *last {
+                continue;
+            }
+            let (year, period_end) = rule.period_of(&nominal);
+            deadlines.push(Deadline {
+                due,
+                date: due.to_string(),
+                weekday: due
+                    .weekday()
+                    .map_err(|e| map_mitra_error(e, "getting weekday"))?,
+                title: rule.title.clone(),
+                period: period_label(rule.frequency, year, period_end)?,
+                moved_from: (nominal != due).then(|| nominal.to_string()),
+            });
+        }
+    }
+    deadlines.sort_by_key(|d| d.due);
+    Ok(deadlines)
+}
+
+/// Names the period ending in `period_end` of `year`.
+fn period_label(frequency: Frequency, year: i32, period_end: u32) -> Result<String> {
+    Ok(match frequency {
+        Frequency::Monthly => ParsiDate::new(year, period_end, 1)
+            .map_err(|e| map_mitra_error(e, "naming fiscal period"))?
+            .format("%B %Y"),
+        Frequency::Quarterly => format!("{} {}", SEASONS[(period_end / 3 - 1) as usize], year),
+        Frequency::Yearly => format!("سال {}", year),
+    })
+}
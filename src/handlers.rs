@@ -9,20 +9,60 @@
 //
 //! Contains the core logic functions (handlers) for each CLI subcommand.
 
-use crate::cli::FormatStyle; // Import needed items from sibling modules
+use crate::academic;
+use crate::anniversary::{self, Origin};
+use crate::applet;
+use crate::astronomy;
+use crate::batch;
+use crate::business_days::{self, DayKind};
+use crate::cli::{BatchOperation, ExportFormat, FeedFormat, FormatStyle, WeekStart}; // Import needed items from sibling modules
+use crate::clock;
+use crate::doctor;
+use crate::duration;
 use crate::events;
-use crate::utils::{map_mitra_error, parse_input_datetime_or_date, print_result};
+use crate::export;
+use crate::feed;
+use crate::fiscal;
+use crate::hijri::{self, HijriDate};
+use crate::ics;
+use crate::info::{DateInfo, InfoField};
+use crate::julian::{self, JulianDate};
+use crate::leave::{self, Exclusion};
+use crate::month_grid::MonthGrid;
+#[cfg(feature = "proleptic")]
+use crate::proleptic;
+use crate::range::{ParsiDateRangeExt, Step};
+use crate::recurrence::RecurrenceRule;
+use crate::rfc3339;
+use crate::rpc;
+use crate::schedule::{self, Schedule};
+use crate::statusbar;
+use crate::timezone::{self, ParsiZonedDateTime};
+use crate::utils::{
+    check_supported_year, humanize_day_span, map_mitra_error, parse_gregorian_input,
+    parse_input_datetime_or_date, print_result, signed_days_between, styled_println,
+    to_ascii_digits,
+};
+use crate::worldclock;
+use crate::zodiac;
 use anyhow::{Context, Result, bail};
 use chrono::Duration; // Use chrono::Duration for time arithmetic
-use parsidate::{ParsiDate, ParsiDateTime};
+use parsidate::{MAX_PARSI_DATE, ParsiDate, ParsiDateTime};
 use std::collections::VecDeque;
+use std::io::{IsTerminal, Write};
 
 // --- Helper Function to Generate Calendar Lines for a Single Month ---
 
 /// Generates the lines of text representing a single month's calendar grid.
 /// Returns a Vec<String>, where each string is a line (header, weekdays, days).
 /// Includes event indicators and today highlighting.
-fn generate_month_lines(year: i32, month: u32, today: &ParsiDate) -> Result<Vec<String>> {
+/// `week_start` selects which weekday occupies the first column.
+fn generate_month_lines(
+    year: i32,
+    month: u32,
+    today: &ParsiDate,
+    week_start: WeekStart,
+) -> Result<Vec<String>> {
     // --- Width Configuration ---
     // Let's use 3 chars per day (e.g., " 5*") + 1 space separator = 4 chars per cell
     // Total width = 7 days * 4 chars/day - 1 trailing space = 27 chars
@@ -34,89 +74,62 @@ fn generate_month_lines(year: i32, month: u32, today: &ParsiDate) -> Result<Vec<
 
     let mut lines: Vec<String> = Vec::with_capacity(8); // Header, weekdays, max 6 weeks
 
-    // Validate month and create first day
+    // Validate month before building the grid
     if !(1..=12).contains(&month) {
         return Ok(vec![format!("Invalid Month: {}", month)]);
     }
-    let first_day_of_month = ParsiDate::new(year, month, 1)
-        .map_err(|e| map_mitra_error(e, &format!("creating date {}-{}-1", year, month)))?;
-
-    // Get month name
-    let month_name = first_day_of_month.format("%B");
-
-    // Get first weekday (0=Sat, 6=Fri)
-    let first_weekday_name = first_day_of_month
-        .weekday()
-        .map_err(|e| map_mitra_error(e, &format!("getting weekday for {}-{}-1", year, month)))?;
-    let first_weekday: u32 = match first_weekday_name.as_str() {
-        "شنبه" => 0,
-        "یکشنبه" => 1,
-        "دوشنبه" => 2,
-        "سه‌شنبه" => 3,
-        "چهارشنبه" => 4,
-        "پنجشنبه" => 5,
-        "جمعه" => 6,
-        _ => bail!("Unexpected weekday name: {}", first_weekday_name),
-    };
-
-    let days_in_month = ParsiDate::days_in_month(year, month);
-    if days_in_month == 0 {
-        return Ok(vec![format!("Invalid days for {}-{}", year, month)]);
-    }
+    let grid = MonthGrid::new(year, month, today, week_start)?;
 
     // --- Build Lines ---
 
     // Header Line (Month Year) - Centered in the new total_width
-    let header = format!("{} {}", month_name, year);
+    let header = format!("{} {}", grid.month_name, year);
     lines.push(format!("{:^width$}", header, width = total_width));
 
-    // Weekday Names Line - Using 3-letter English abbreviations
+    // Weekday Names Line - Using 3-letter English abbreviations, rotated to the week start
     // Each abbreviation takes 3 chars. Need padding to match cell_width (4). Add 1 space.
-    lines.push(" Sat Sun Mon Tue Wed Thu Fri".to_string()); // 7 * 3 chars + 6 spaces = 27 width
+    let weekday_abbrevs = ["Sat", "Sun", "Mon", "Tue", "Wed", "Thu", "Fri"];
+    let weekday_header: String = (0..7)
+        .map(|i| {
+            format!(
+                " {}",
+                weekday_abbrevs[(week_start.offset() as usize + i) % 7]
+            )
+        })
+        .collect();
+    lines.push(weekday_header); // e.g. " Sat Sun Mon Tue Wed Thu Fri"
     // Alternative Persian: "  ش  ی  د  س  چ  پ  ج" (adjust spacing)
 
     // Days Lines
-    let mut current_line = String::with_capacity(total_width);
-    // Add padding for the first week based on cell_width
-    current_line.push_str(&" ".repeat((first_weekday * cell_width as u32) as usize));
-
-    let is_current_month_year = year == today.year() && month == today.month();
-    let today_day_num = if is_current_month_year {
-        Some(today.day())
-    } else {
-        None
-    };
-
-    for day in 1..=days_in_month {
-        let is_today = today_day_num == Some(day);
-        let event_indicator = events::get_event_indicator(year, month, day).unwrap_or(' ');
-
-        let start_highlight = if is_today { "\x1b[7m" } else { "" }; // Reverse video
-        let end_highlight = if is_today { "\x1b[0m" } else { "" }; // Reset
-
-        // Format: HighlightStart Day(width) Indicator HighlightEnd Padding
-        current_line.push_str(&format!(
-            "{}{:width$}{}{}{}", // Day number right-aligned in `day_width`
-            start_highlight,
-            day,
-            event_indicator,
-            end_highlight,
-            " ".repeat(cell_padding), // Add padding after the cell
-            width = day_width
-        ));
-
-        let current_weekday = (first_weekday + day - 1) % 7;
-
-        if current_weekday == 6 || day == days_in_month {
-            // End of week or end of month, push the current line (trim trailing space and pad right)
-            lines.push(format!(
-                "{:<width$}",
-                current_line.trim_end(),
-                width = total_width
+    for week in &grid.weeks {
+        let mut current_line = String::with_capacity(total_width);
+        for cell in week {
+            let Some(cell) = cell else {
+                // Empty cell before the first or after the last day of the month
+                current_line.push_str(&" ".repeat(cell_width));
+                continue;
+            };
+
+            let start_highlight = if cell.is_today { "\x1b[7m" } else { "" }; // Reverse video
+            let end_highlight = if cell.is_today { "\x1b[0m" } else { "" }; // Reset
+
+            // Format: HighlightStart Day(width) Indicator HighlightEnd Padding
+            current_line.push_str(&format!(
+                "{}{:width$}{}{}{}", // Day number right-aligned in `day_width`
+                start_highlight,
+                cell.day,
+                cell.indicator(),
+                end_highlight,
+                " ".repeat(cell_padding), // Add padding after the cell
+                width = day_width
             ));
-            current_line.clear(); // Start new line
         }
-        // No "else { print!(" ") }" needed as padding is part of the cell format now
+        // Trim trailing space and pad right so every week has the same width
+        lines.push(format!(
+            "{:<width$}",
+            current_line.trim_end(),
+            width = total_width
+        ));
     }
 
     // Ensure all months have the same number of lines (e.g., 8 lines total) for alignment
@@ -128,12 +141,98 @@ fn generate_month_lines(year: i32, month: u32, today: &ParsiDate) -> Result<Vec<
     Ok(lines)
 }
 
+/// Generates the lines listing every event in a month, one per event with its day number
+/// and a holiday marker, under a "Month Year:" heading.
+fn generate_month_event_lines(year: i32, month: u32) -> Result<Vec<String>> {
+    let first_day_of_month = ParsiDate::new(year, month, 1)
+        .map_err(|e| map_mitra_error(e, &format!("creating date {}-{}-1", year, month)))?;
+
+    let mut lines = vec![format!("{} {}:", first_day_of_month.format("%B"), year)];
+    for day in 1..=ParsiDate::days_in_month(year, month) {
+        for event in events::get_events_for_date(year, month, day).unwrap_or_default() {
+            let prefix = if event.holiday { "[تعطیل] " } else { "- " };
+            lines.push(format!("  {:>2} {}{}", day, prefix, event.title));
+        }
+    }
+    if lines.len() == 1 {
+        lines.push("  - No events found.".to_string());
+    }
+    Ok(lines)
+}
+
 // --- Command Handler Functions ---
 
 /// Handles the `now` command: Fetches and prints the current Parsi date and time.
-pub fn handle_now() -> Result<()> {
-    let now = ParsiDateTime::now().context("Failed to get current Parsi datetime")?;
-    println!("{}", now); // Uses ParsiDateTime's Display trait
+/// With `tz`, prints the wall-clock time in that zone followed by its offset and name.
+pub fn handle_now(tz: Option<String>) -> Result<()> {
+    if let Some(name) = tz {
+        let zoned = ParsiZonedDateTime::from_utc(clock::utc_now()?, timezone::resolve(&name)?)?;
+        styled_println!("{}", zoned);
+        return Ok(());
+    }
+    let now = clock::now().context("Failed to get current Parsi datetime")?;
+    styled_println!("{}", now); // Uses ParsiDateTime's Display trait
+    Ok(())
+}
+
+/// Default `stamp` pattern when neither `--pattern` nor `MITRA_STAMP_PATTERN` is given.
+const DEFAULT_STAMP_PATTERN: &str = "%Y%m%d-%H%M%S";
+
+/// Handles the `stamp` command: Prints the current Parsi date and time formatted for use in
+/// file names. Digits are forced to ASCII, and characters that are unsafe in file names
+/// (path separators, `:*?"<>|`, whitespace, and control characters) become `-`.
+pub fn handle_stamp(pattern_opt: Option<String>) -> Result<()> {
+    let pattern = pattern_opt
+        .or_else(|| std::env::var("MITRA_STAMP_PATTERN").ok())
+        .unwrap_or_else(|| DEFAULT_STAMP_PATTERN.to_string());
+    let now = clock::now().context("Failed to get current Parsi datetime")?;
+
+    let stamp: String = to_ascii_digits(&now.format(&pattern))
+        .chars()
+        .map(|c| match c {
+            '/' | '\\' | ':' | '*' | '?' | '"' | '<' | '>' | '|' => '-',
+            c if c.is_whitespace() || c.is_control() || c == '\u{200C}' => '-',
+            c => c,
+        })
+        .collect();
+    if stamp.is_empty() || stamp == "." || stamp == ".." {
+        bail!(
+            "Error: The stamp pattern '{}' does not produce a usable file name.",
+            pattern
+        );
+    }
+    println!("{}", stamp);
+    Ok(())
+}
+
+/// Zone of the Parsi side of `timestamp`, and of UTC times read by `ics`, when `--tz` is
+/// not given.
+const DEFAULT_TZ: &str = "Asia/Tehran";
+
+/// Handles the `timestamp` command: Converts a Unix timestamp (an integer, possibly
+/// negative) to Parsi wall-clock time in `tz`, or any other input, read as an RFC 3339
+/// timestamp or a Parsi date/datetime in `tz`, to a Unix timestamp. Without input, prints
+/// the current timestamp.
+pub fn handle_timestamp(value: Option<String>, tz: Option<String>) -> Result<()> {
+    let Some(value) = value else {
+        println!("{}", clock::utc_now()?.timestamp());
+        return Ok(());
+    };
+    let tz = timezone::resolve(tz.as_deref().unwrap_or(DEFAULT_TZ))?;
+
+    if let Ok(secs) = to_ascii_digits(value.trim()).parse::<i64>() {
+        let zoned = ParsiZonedDateTime::from_unix_timestamp(secs, tz)?;
+        styled_println!("{}", zoned);
+    } else if let Ok((pdt, offset)) = rfc3339::parse_rfc3339(&value) {
+        println!("{}", rfc3339::to_utc(&pdt, offset)?.timestamp());
+    } else {
+        let (pdt, _) = parse_input_datetime_or_date(&value)
+            .with_context(|| format!("Failed to parse Unix timestamp or Parsi date: {}", value))?;
+        println!(
+            "{}",
+            ParsiZonedDateTime::from_local(pdt, tz)?.to_unix_timestamp()
+        );
+    }
     Ok(())
 }
 
@@ -143,19 +242,25 @@ pub fn handle_cal(
     year_opt: Option<i32>, // Year for single month view
     three_months: bool,
     year_to_show_opt: Option<i32>, // Year for full year view (-y)
+    week_start: WeekStart,
+    show_events: bool, // List each displayed month's events after the grids
 ) -> Result<()> {
-    let today = ParsiDate::today().context("Failed to get today's date")?;
+    let today = clock::today().context("Failed to get today's date")?;
+    // (year, month) pairs in display order, used for the optional events listing.
+    let mut shown_months: Vec<(i32, u32)> = Vec::with_capacity(12);
 
     // --- Determine Mode and Target Date(s) ---
 
     if let Some(year_to_show) = year_to_show_opt {
         // === Full Year Mode ===
-        println!("{:^64}", year_to_show); // Center year title over roughly 3 months width
+        check_supported_year(year_to_show)?;
+        styled_println!("{:^64}", year_to_show); // Center year title over roughly 3 months width
 
         let mut month_lines: Vec<VecDeque<String>> = Vec::with_capacity(12);
         for m in 1..=12 {
-            let lines = generate_month_lines(year_to_show, m, &today)?;
+            let lines = generate_month_lines(year_to_show, m, &today, week_start)?;
             month_lines.push(lines.into()); // Convert Vec<String> to VecDeque for easy pop_front
+            shown_months.push((year_to_show, m));
         }
 
         // Print months in 3 columns, 4 rows
@@ -182,7 +287,7 @@ pub fn handle_cal(
                         }
                     }
                 }
-                println!("{}", row_line);
+                styled_println!("{}", row_line);
             }
             // Remove the first 3 months for the next row
             month_lines.drain(0..std::cmp::min(3, month_lines.len()));
@@ -208,15 +313,20 @@ pub fn handle_cal(
         };
 
         // Generate lines for all three months
-        let prev_lines = generate_month_lines(prev_year, prev_month, &today)?;
-        let current_lines = generate_month_lines(target_year, target_month, &today)?;
-        let next_lines = generate_month_lines(next_year, next_month, &today)?;
+        let prev_lines = generate_month_lines(prev_year, prev_month, &today, week_start)?;
+        let current_lines = generate_month_lines(target_year, target_month, &today, week_start)?;
+        let next_lines = generate_month_lines(next_year, next_month, &today, week_start)?;
+        shown_months.extend([
+            (prev_year, prev_month),
+            (target_year, target_month),
+            (next_year, next_month),
+        ]);
 
         // Print side-by-side (assuming all Vecs have same length due to padding)
         for i in 0..prev_lines.len() {
             // Use length of first vec (should be 8)
             // Format: PrevMonthLines  CurrentMonthLines  NextMonthLines
-            println!(
+            styled_println!(
                 "{}  {}  {}",
                 prev_lines.get(i).map_or("", |s| s.as_str()), // Use get() for safety
                 current_lines.get(i).map_or("", |s| s.as_str()),
@@ -235,6 +345,7 @@ pub fn handle_cal(
             target_month = month_num;
             // Year is optional if month is provided, default to current year if needed
             target_year = year_opt.unwrap_or_else(|| today.year());
+            check_supported_year(target_year)?;
             // Validate month range (already done in generate_month_lines, but good here too)
             if !(1..=12).contains(&target_month) {
                 bail!("Error: Month must be between 1 and 12.");
@@ -252,18 +363,30 @@ pub fn handle_cal(
 
         // --- Generate and Print ---
         // Now that target_year and target_month are determined, generate lines
-        let lines = generate_month_lines(target_year, target_month, &today)?;
+        let lines = generate_month_lines(target_year, target_month, &today, week_start)?;
         for line in lines {
-            println!("{}", line);
+            styled_println!("{}", line);
         }
+        shown_months.push((target_year, target_month));
     } // End of else block for single month mode
 
+    // Optional: List the events of every displayed month below the grids
+    if show_events {
+        for (year, month) in shown_months {
+            println!();
+            for line in generate_month_event_lines(year, month)? {
+                styled_println!("{}", line);
+            }
+        }
+    }
+
     // Optional: Add legend for indicators
-    println!("\n*: Holiday  +: Other Event");
+    styled_println!("\n*: Holiday  +: Other Event");
 
     Ok(())
 } // End of handle_cal function
 /// Handles the `add` command: Adds a specified duration to a base date/datetime.
+#[allow(clippy::too_many_arguments)] // One optional argument per duration unit.
 pub fn handle_add(
     base_dt_str: String,
     days: Option<i64>,
@@ -272,6 +395,7 @@ pub fn handle_add(
     hours: Option<i64>,
     minutes: Option<i64>,
     seconds: Option<i64>,
+    business_days: Option<i64>,
 ) -> Result<()> {
     // Validate that exactly one duration unit is provided (clap also helps here).
     let unit_count = [
@@ -281,6 +405,7 @@ pub fn handle_add(
         hours,
         minutes,
         seconds,
+        business_days,
     ]
     .iter()
     .filter(|opt| opt.is_some())
@@ -288,7 +413,7 @@ pub fn handle_add(
 
     if unit_count == 0 {
         bail!(
-            "Error: Please specify exactly one duration unit (--days, --months, --years, --hours, --minutes, or --seconds) to add."
+            "Error: Please specify exactly one duration unit (--days, --months, --years, --hours, --minutes, --seconds, or --business-days) to add."
         );
     }
     if unit_count > 1 {
@@ -323,6 +448,12 @@ pub fn handle_add(
         base_pdt
             .add_duration(Duration::seconds(s))
             .map_err(|e| map_mitra_error(e, "adding seconds"))?
+    } else if let Some(b) = business_days {
+        // Shift by the calendar days the business days span, keeping any time part.
+        let due = business_days::add_business_days(&base_pdt.date(), b)?;
+        base_pdt
+            .add_days(signed_days_between(&base_pdt.date(), &due)?)
+            .map_err(|e| map_mitra_error(e, "adding business days"))?
     } else {
         unreachable!("Logic error: No duration unit found.");
     };
@@ -333,6 +464,7 @@ pub fn handle_add(
 }
 
 /// Handles the `sub` command: Subtracts a specified duration from a base date/datetime.
+#[allow(clippy::too_many_arguments)] // One optional argument per duration unit.
 pub fn handle_sub(
     base_dt_str: String,
     days: Option<u64>,
@@ -341,6 +473,7 @@ pub fn handle_sub(
     hours: Option<u64>,
     minutes: Option<u64>,
     seconds: Option<u64>,
+    business_days: Option<u64>,
 ) -> Result<()> {
     // Validate input unit count.
     let unit_count = [
@@ -350,6 +483,7 @@ pub fn handle_sub(
         hours.map(|u| u as i64),
         minutes.map(|u| u as i64),
         seconds.map(|u| u as i64),
+        business_days.map(|u| u as i64),
     ]
     .iter()
     .filter(|opt| opt.is_some())
@@ -357,7 +491,7 @@ pub fn handle_sub(
 
     if unit_count == 0 {
         bail!(
-            "Error: Please specify exactly one duration unit (--days, --months, --years, --hours, --minutes, or --seconds) to subtract."
+            "Error: Please specify exactly one duration unit (--days, --months, --years, --hours, --minutes, --seconds, or --business-days) to subtract."
         );
     }
     if unit_count > 1 {
@@ -402,6 +536,11 @@ pub fn handle_sub(
         base_pdt
             .sub_duration(Duration::seconds(s_i64))
             .map_err(|e| map_mitra_error(e, "subtracting seconds"))?
+    } else if let Some(b) = business_days {
+        let due = business_days::sub_business_days(&base_pdt.date(), b)?;
+        base_pdt
+            .add_days(signed_days_between(&base_pdt.date(), &due)?)
+            .map_err(|e| map_mitra_error(e, "subtracting business days"))?
     } else {
         unreachable!();
     };
@@ -453,47 +592,318 @@ pub fn handle_format(
         }
     };
 
-    println!("{}", formatted_string);
+    styled_println!("{}", formatted_string);
     Ok(())
 }
 
-/// Handles the `diff` command: Calculates the difference in days between two dates.
-pub fn handle_diff(dt_str1: String, dt_str2: String) -> Result<()> {
+/// Handles the `diff` command: Calculates the difference in days between two dates,
+/// followed by a years/months/days/time breakdown that includes any time parts.
+/// With `business_days`, counts only Saturdays to Thursdays that are not official holidays.
+/// With `raw`, prints only the number of days.
+pub fn handle_diff(dt_str1: String, dt_str2: String, business_days: bool, raw: bool) -> Result<()> {
     let (pdt1, _) = parse_input_datetime_or_date(&dt_str1)
         .with_context(|| format!("Failed to parse first date/datetime: {}", dt_str1))?;
     let (pdt2, _) = parse_input_datetime_or_date(&dt_str2)
         .with_context(|| format!("Failed to parse second date/datetime: {}", dt_str2))?;
 
+    if business_days {
+        // Count from the earlier date so the result does not depend on argument order.
+        let (first, last) = if pdt2 < pdt1 {
+            (pdt2.date(), pdt1.date())
+        } else {
+            (pdt1.date(), pdt2.date())
+        };
+        let count = business_days::business_days_between(&first, &last)?;
+        if raw {
+            println!("{}", count);
+        } else {
+            println!("Difference: {} business days", count);
+        }
+        return Ok(());
+    }
+
     // Calculate difference in days using ParsiDate::days_between (absolute value).
     let days_diff = pdt1
         .date()
         .days_between(&pdt2.date())
         .map_err(|e| map_mitra_error(e, "calculating date difference"))?;
 
-    println!("Difference: {} days", days_diff);
+    if raw {
+        println!("{}", days_diff);
+    } else {
+        println!("Difference: {} days", days_diff);
+        println!(
+            "Breakdown: {}",
+            duration::get_duration_breakdown(&pdt1, &pdt2)?
+        );
+    }
     Ok(())
 }
 
-/// Handles the `weekday` command: Prints the Persian weekday name for a given date.
-pub fn handle_weekday(date_str: String) -> Result<()> {
-    // Parse input, ignore time part.
-    let (pdt, _) = parse_input_datetime_or_date(&date_str)
+/// Parses `date_str` and returns the signed number of days from today to it.
+fn days_from_today(date_str: &str) -> Result<(ParsiDate, i64)> {
+    let (pdt, _) = parse_input_datetime_or_date(date_str)
         .with_context(|| format!("Failed to parse date: {}", date_str))?;
+    let today = clock::today().context("Failed to get today's date")?;
 
-    // Get weekday name.
-    let weekday_name = pdt
-        .date()
-        .weekday()
-        .map_err(|e| map_mitra_error(e, "getting weekday"))?;
+    let date = pdt.date();
+    Ok((date, signed_days_between(&today, &date)?))
+}
+
+/// Handles the `until` command: Prints how many days remain until a date, e.g.
+/// "۴۵ روز مانده" ("امروز" for today). Past dates are rejected in favor of `since`.
+pub fn handle_until(date_str: String) -> Result<()> {
+    let (date, days) = days_from_today(&date_str)?;
+    if days < 0 {
+        bail!(
+            "Error: {} has already passed ({}). Use `mitra since` for elapsed time.",
+            date,
+            humanize_day_span(days)
+        );
+    }
+    println!("{}", humanize_day_span(days));
+    Ok(())
+}
+
+/// Handles the `since` command: Prints how many days have passed since a date, e.g.
+/// "۱۲ روز گذشته" ("امروز" for today). Future dates are rejected in favor of `until`.
+pub fn handle_since(date_str: String) -> Result<()> {
+    let (date, days) = days_from_today(&date_str)?;
+    if days > 0 {
+        bail!(
+            "Error: {} is still ahead ({}). Use `mitra until` for remaining time.",
+            date,
+            humanize_day_span(days)
+        );
+    }
+    println!("{}", humanize_day_span(days));
+    Ok(())
+}
+
+/// Handles the `weekday` command: Prints the Persian weekday name for one or more dates.
+/// A single date prints just the name; several dates or a `--from/--to` range print
+/// one `date → weekday` line per day. With `raw`, only the names are printed, one per line.
+pub fn handle_weekday(
+    date_strs: Vec<String>,
+    from: Option<String>,
+    to: Option<String>,
+    raw: bool,
+) -> Result<()> {
+    // Collect the dates to report, either from the range or from the positional list.
+    let dates: Vec<ParsiDate> = if let (Some(from_str), Some(to_str)) = (from, to) {
+        let (start, _) = parse_input_datetime_or_date(&from_str)
+            .with_context(|| format!("Failed to parse range start: {}", from_str))?;
+        let (end, _) = parse_input_datetime_or_date(&to_str)
+            .with_context(|| format!("Failed to parse range end: {}", to_str))?;
+        let (start, end) = (start.date(), end.date());
+        if start > end {
+            bail!("Error: Range start {} is after range end {}.", start, end);
+        }
+
+        start.iter_to(end).collect()
+    } else {
+        date_strs
+            .iter()
+            .map(|date_str| {
+                parse_input_datetime_or_date(date_str)
+                    .map(|(pdt, _)| pdt.date())
+                    .with_context(|| format!("Failed to parse date: {}", date_str))
+            })
+            .collect::<Result<_>>()?
+    };
 
-    println!("{}", weekday_name);
+    // Keep the plain single-value output when only one date was given.
+    let show_dates = dates.len() > 1 && !raw;
+    for date in dates {
+        let weekday_name = date
+            .weekday()
+            .map_err(|e| map_mitra_error(e, "getting weekday"))?;
+        if show_dates {
+            println!("{} → {}", date, weekday_name);
+        } else {
+            println!("{}", weekday_name);
+        }
+    }
+    Ok(())
+}
+
+/// Handles the `range` command: Prints the dates from `start` through `end` (or the first
+/// `count` dates from `start`), `every` apart, one per line; newest first with `reverse`.
+pub fn handle_range(
+    start: String,
+    end: Option<String>,
+    every: String,
+    count: Option<usize>,
+    reverse: bool,
+) -> Result<()> {
+    let (start_pdt, _) = parse_input_datetime_or_date(&start)
+        .with_context(|| format!("Failed to parse range start: {}", start))?;
+    let range = match end {
+        Some(end) => {
+            let (end_pdt, _) = parse_input_datetime_or_date(&end)
+                .with_context(|| format!("Failed to parse range end: {}", end))?;
+            if start_pdt.date() > end_pdt.date() {
+                bail!(
+                    "Error: Range start {} is after range end {}.",
+                    start_pdt.date(),
+                    end_pdt.date()
+                );
+            }
+            start_pdt.date().iter_to(end_pdt.date())
+        }
+        None => start_pdt.date().iter_days(),
+    };
+    let range = match Step::parse(&every)? {
+        Step::Days(days) => range.step_by_days(days),
+        Step::Weeks(weeks) => range.step_by_weeks(weeks),
+        Step::Months(months) => range.step_by_months(months),
+    };
+
+    let limit = count.unwrap_or(usize::MAX);
+    let dates: Box<dyn Iterator<Item = ParsiDate>> = if reverse {
+        Box::new(range.rev().take(limit))
+    } else {
+        Box::new(range.take(limit))
+    };
+    for date in dates {
+        println!("{}", date);
+    }
+    Ok(())
+}
+
+/// How far `recur` looks ahead when no end date is given.
+const RECUR_DEFAULT_YEARS: i32 = 100;
+
+/// Handles the `recur` command: Prints the occurrences of `rule` from `start` through `end`
+/// (or the next `RECUR_DEFAULT_YEARS` years), at most `count` of them, one per line, or
+/// with `ics` as an iCalendar event titled `summary`.
+pub fn handle_recur(
+    rule_text: String,
+    start: String,
+    end: Option<String>,
+    count: Option<usize>,
+    ics: bool,
+    summary: Option<String>,
+) -> Result<()> {
+    let rule = RecurrenceRule::parse(&rule_text)?;
+    let start = parse_input_datetime_or_date(&start)
+        .with_context(|| format!("Failed to parse series start: {}", start))?
+        .0
+        .date();
+    let end = match end {
+        Some(end) => parse_input_datetime_or_date(&end)
+            .with_context(|| format!("Failed to parse series end: {}", end))?
+            .0
+            .date(),
+        None => start
+            .add_years(RECUR_DEFAULT_YEARS)
+            .unwrap_or(MAX_PARSI_DATE),
+    };
+    if start > end {
+        bail!("Error: Series start {} is after series end {}.", start, end);
+    }
+
+    let dates = rule
+        .occurrences(start, end)?
+        .take(count.unwrap_or(usize::MAX));
+    if ics {
+        let dates: Vec<ParsiDate> = dates.collect();
+        return ics::write_series(
+            &mut std::io::stdout().lock(),
+            &rule_text,
+            &dates,
+            summary.as_deref(),
+            clock::utc_now()?,
+        );
+    }
+    for date in dates {
+        println!("{}", date);
+    }
+    Ok(())
+}
+
+/// Handles the `ics` command: Prints the dates of every event in the calendar `file` (or
+/// stdin for `-`) from `from` (today) through `to` (a year later), as `date  summary`
+/// lines in date order.
+pub fn handle_ics(
+    file: std::path::PathBuf,
+    from: Option<String>,
+    to: Option<String>,
+    tz: Option<String>,
+) -> Result<()> {
+    let content = if file.as_os_str() == "-" {
+        std::io::read_to_string(std::io::stdin()).context("Failed to read calendar from stdin")?
+    } else {
+        std::fs::read_to_string(&file)
+            .with_context(|| format!("Failed to read calendar file: {}", file.display()))?
+    };
+    let tz = timezone::resolve(tz.as_deref().unwrap_or(DEFAULT_TZ))?;
+    let from = match from {
+        Some(from) => parse_input_datetime_or_date(&from)
+            .with_context(|| format!("Failed to parse start date: {}", from))?
+            .0
+            .date(),
+        None => clock::today()?,
+    };
+    let to = match to {
+        Some(to) => parse_input_datetime_or_date(&to)
+            .with_context(|| format!("Failed to parse end date: {}", to))?
+            .0
+            .date(),
+        None => from.add_years(1).unwrap_or(MAX_PARSI_DATE),
+    };
+    if from > to {
+        bail!("Error: Start date {} is after end date {}.", from, to);
+    }
+
+    let mut lines = Vec::new();
+    for event in ics::parse_calendar(&content, tz)? {
+        for date in event.occurrences(from, to)? {
+            lines.push((date, event.summary.clone()));
+        }
+    }
+    lines.sort();
+    for (date, summary) in lines {
+        if summary.is_empty() {
+            println!("{}", date);
+        } else {
+            println!("{}  {}", date, summary);
+        }
+    }
     Ok(())
 }
 
 /// Handles the `to-gregorian` command: Converts a Parsi date/datetime to Gregorian.
-pub fn handle_to_gregorian(parsi_dt_str: String) -> Result<()> {
-    let (pdt, was_datetime) = parse_input_datetime_or_date(&parsi_dt_str)
-        .with_context(|| format!("Failed to parse Parsi date/datetime: {}", parsi_dt_str))?;
+/// With `tz`, the input is wall-clock time in that zone (midnight for a bare date), and the
+/// output is that instant in `to_tz` (or `tz`), with its UTC offset, or as an RFC 3339
+/// timestamp with `rfc3339`.
+pub fn handle_to_gregorian(
+    parsi_dt_str: String,
+    tz: Option<String>,
+    to_tz: Option<String>,
+    rfc3339: bool,
+) -> Result<()> {
+    let (pdt, was_datetime) = match parse_input_datetime_or_date(&parsi_dt_str)
+        .with_context(|| format!("Failed to parse Parsi date/datetime: {}", parsi_dt_str))
+    {
+        Ok(parsed) => parsed,
+        Err(e) => return proleptic_to_gregorian(&parsi_dt_str, e),
+    };
+
+    if let Some(name) = tz {
+        let source = timezone::resolve(&name)?;
+        let target = to_tz.map_or(Ok(source), |name| timezone::resolve(&name))?;
+        let zoned = ParsiZonedDateTime::from_local(pdt, source)?.with_timezone(target)?;
+        if rfc3339 {
+            println!(
+                "{}",
+                rfc3339::format_rfc3339(&zoned.datetime(), zoned.offset())?
+            );
+        } else {
+            println!("{}", zoned.to_gregorian().format("%Y-%m-%d %H:%M:%S %:z"));
+        }
+        return Ok(());
+    }
 
     // Convert.
     let gregorian_ndt = pdt
@@ -510,106 +920,498 @@ pub fn handle_to_gregorian(parsi_dt_str: String) -> Result<()> {
 }
 
 /// Handles the `from-gregorian` command: Converts a Gregorian date/datetime to Parsi.
-pub fn handle_from_gregorian(gregorian_dt_str: String) -> Result<()> {
-    let trimmed_input = gregorian_dt_str.trim();
-    let mut was_datetime = false; // Track if the input included time
-
-    // Try parsing common Gregorian formats (ISO and slash, DateTime first).
-    let gregorian_ndt = chrono::NaiveDateTime::parse_from_str(trimmed_input, "%Y-%m-%d %H:%M:%S")
-        .or_else(|_| chrono::NaiveDateTime::parse_from_str(trimmed_input, "%Y-%m-%dT%H:%M:%S"))
-        .or_else(|_| chrono::NaiveDateTime::parse_from_str(trimmed_input, "%Y/%m/%d %H:%M:%S"))
-        .inspect(|_ndt| {
-            was_datetime = true; // Successfully parsed as DateTime
-        })
-        .or_else(|_| {
-            // If DateTime parsing fails, try parsing as NaiveDate.
-            chrono::NaiveDate::parse_from_str(trimmed_input, "%Y-%m-%d")
-                .or_else(|_| chrono::NaiveDate::parse_from_str(trimmed_input, "%Y/%m/%d"))
-                .map(|nd| {
-                    was_datetime = false; // Successfully parsed as Date
-                    // Convert NaiveDate to NaiveDateTime at midnight.
-                    nd.and_hms_opt(0, 0, 0).unwrap() // 00:00:00 is always valid
-                })
-        })
-        // If both fail, return an error.
-        .with_context(|| format!("Could not parse Gregorian date/datetime '{}'. Use formats like YYYY-MM-DD, YYYY-MM-DD HH:MM:SS, or YYYY-MM-DDTHH:MM:SS", trimmed_input))?;
+/// With `tz`, the input is wall-clock time in that zone (midnight for a bare date), and the
+/// output is that instant in `to_tz` (or `tz`), with its UTC offset and zone. An RFC 3339
+/// input names its own instant, so `tz` then only sets the default output zone.
+pub fn handle_from_gregorian(
+    gregorian_dt_str: String,
+    tz: Option<String>,
+    to_tz: Option<String>,
+) -> Result<()> {
+    let (gregorian_ndt, was_datetime) = parse_gregorian_input(&gregorian_dt_str)?;
+
+    if let Some(name) = tz {
+        let source = timezone::resolve(&name)?;
+        let target = to_tz.map_or(Ok(source), |name| timezone::resolve(&name))?;
+        let instant = match rfc3339::parse_rfc3339(&gregorian_dt_str) {
+            Ok((pdt, offset)) => rfc3339::to_utc(&pdt, offset)?,
+            Err(_) => timezone::localize(source, gregorian_ndt)?.with_timezone(&chrono::Utc),
+        };
+        println!("{}", ParsiZonedDateTime::from_utc(instant, target)?);
+        return Ok(());
+    }
 
     // Convert the parsed Gregorian NaiveDateTime to ParsiDateTime.
-    let parsi_pdt = ParsiDateTime::from_gregorian(gregorian_ndt)
-        .map_err(|e| map_mitra_error(e, "converting from Gregorian"))?;
+    let parsi_pdt = match ParsiDateTime::from_gregorian(gregorian_ndt)
+        .map_err(|e| map_mitra_error(e, "converting from Gregorian"))
+    {
+        Ok(pdt) => pdt,
+        Err(e) => return proleptic_from_gregorian(gregorian_ndt, was_datetime, e),
+    };
 
     // Print the result based on whether the input seemed like a datetime or just a date.
     print_result(parsi_pdt, was_datetime);
     Ok(())
 }
 
-/// Handles the `is-leap` command: Checks if a Parsi year is a leap year.
-pub fn handle_is_leap(year: i32) -> Result<()> {
-    if year <= 0 {
-        bail!("Error: Year must be a positive number.");
+/// Handles the `to-julian` command: Converts a Parsi date/datetime to the Julian calendar.
+pub fn handle_to_julian(parsi_dt_str: String) -> Result<()> {
+    let (pdt, was_datetime) = parse_input_datetime_or_date(&parsi_dt_str)
+        .with_context(|| format!("Failed to parse Parsi date/datetime: {}", parsi_dt_str))?;
+    let gregorian_ndt = pdt
+        .to_gregorian()
+        .map_err(|e| map_mitra_error(e, "converting to Gregorian"))?;
+    let julian_date = JulianDate::from_gregorian(gregorian_ndt.date())?;
+
+    if was_datetime {
+        println!("{} {}", julian_date, gregorian_ndt.format("%H:%M:%S"));
+    } else {
+        println!("{}", julian_date);
     }
-    let is_leap = ParsiDate::is_persian_leap_year(year);
-    println!("{}", if is_leap { "Yes" } else { "No" });
     Ok(())
 }
 
-/// Handles the `info` command: Displays detailed information about a date/datetime.
-pub fn handle_info(datetime_string: String) -> Result<()> {
-    let (pdt, was_datetime) = parse_input_datetime_or_date(&datetime_string)?;
+/// Handles the `from-julian` command: Converts a Julian date/datetime to Parsi.
+/// Like `from-gregorian`, dates before year 1 of the Parsi calendar fall back to
+/// proleptic output when that feature is enabled.
+pub fn handle_from_julian(julian_dt_str: String) -> Result<()> {
+    let (julian_date, time, was_datetime) = julian::parse_input(&julian_dt_str)?;
+    let gregorian_ndt = julian_date.to_gregorian()?.and_time(time);
+
+    let parsi_pdt = match ParsiDateTime::from_gregorian(gregorian_ndt)
+        .map_err(|e| map_mitra_error(e, "converting from Julian"))
+    {
+        Ok(pdt) => pdt,
+        Err(e) => return proleptic_from_gregorian(gregorian_ndt, was_datetime, e),
+    };
+    print_result(parsi_pdt, was_datetime);
+    Ok(())
+}
 
-    println!("Input Parsi Date/Time: {}", datetime_string);
-    println!("-------------------------");
+/// Handles the `to-hijri` command: Converts a Parsi (or, with `gregorian`, Gregorian)
+/// date/datetime to the tabular Hijri calendar.
+pub fn handle_to_hijri(datetime_string: String, gregorian: bool) -> Result<()> {
+    let (gregorian_ndt, was_datetime) = if gregorian {
+        parse_gregorian_input(&datetime_string)?
+    } else {
+        let (pdt, was_datetime) = parse_input_datetime_or_date(&datetime_string)
+            .with_context(|| format!("Failed to parse Parsi date/datetime: {}", datetime_string))?;
+        let gregorian_ndt = pdt
+            .to_gregorian()
+            .map_err(|e| map_mitra_error(e, "converting to Gregorian"))?;
+        (gregorian_ndt, was_datetime)
+    };
+    let hijri_date = HijriDate::from_gregorian(gregorian_ndt.date())?;
 
-    // Basic Components
-    println!(" Parsed Date: {}", pdt.date());
     if was_datetime {
+        println!("{} {}", hijri_date, gregorian_ndt.format("%H:%M:%S"));
+    } else {
+        println!("{}", hijri_date);
+    }
+    Ok(())
+}
+
+/// Handles the `from-hijri` command: Converts a tabular Hijri date/datetime to Parsi
+/// (or, with `gregorian`, to Gregorian).
+pub fn handle_from_hijri(hijri_dt_str: String, gregorian: bool) -> Result<()> {
+    let (hijri_date, time, was_datetime) = hijri::parse_input(&hijri_dt_str)?;
+    let gregorian_ndt = hijri_date.to_gregorian()?.and_time(time);
+
+    if gregorian {
+        let pattern = if was_datetime {
+            "%Y-%m-%d %H:%M:%S"
+        } else {
+            "%Y-%m-%d"
+        };
+        println!("{}", gregorian_ndt.format(pattern));
+        return Ok(());
+    }
+    let parsi_pdt = ParsiDateTime::from_gregorian(gregorian_ndt)
+        .map_err(|e| map_mitra_error(e, "converting from Hijri"))?;
+    print_result(parsi_pdt, was_datetime);
+    Ok(())
+}
+
+/// Retries a failed `to-gregorian` input as a proleptic date before year 1.
+/// Inputs that are not such a date keep their original error.
+#[cfg(feature = "proleptic")]
+fn proleptic_to_gregorian(input: &str, parse_error: anyhow::Error) -> Result<()> {
+    match proleptic::parse_components(input) {
+        Some((year, month, day)) if year < 1 => {
+            let date = proleptic::ProlepticDate::new(year, month, day)?;
+            println!("{}", date.to_gregorian()?.format("%Y-%m-%d"));
+            Ok(())
+        }
+        _ => Err(parse_error),
+    }
+}
+
+#[cfg(not(feature = "proleptic"))]
+fn proleptic_to_gregorian(_input: &str, parse_error: anyhow::Error) -> Result<()> {
+    Err(parse_error)
+}
+
+/// Retries a failed `from-gregorian` conversion of a date before 622-03-21 as a
+/// proleptic Parsi date. Other failures keep their original error.
+#[cfg(feature = "proleptic")]
+fn proleptic_from_gregorian(
+    gregorian_ndt: chrono::NaiveDateTime,
+    was_datetime: bool,
+    conversion_error: anyhow::Error,
+) -> Result<()> {
+    if gregorian_ndt.date() >= proleptic::epoch() {
+        return Err(conversion_error);
+    }
+    let date = proleptic::ProlepticDate::from_gregorian(gregorian_ndt.date())?;
+    if was_datetime {
+        println!("{} {}", date, gregorian_ndt.format("%H:%M:%S"));
+    } else {
+        println!("{}", date);
+    }
+    Ok(())
+}
+
+#[cfg(not(feature = "proleptic"))]
+fn proleptic_from_gregorian(
+    _gregorian_ndt: chrono::NaiveDateTime,
+    _was_datetime: bool,
+    conversion_error: anyhow::Error,
+) -> Result<()> {
+    Err(conversion_error)
+}
+
+/// Handles the `is-leap` command: Checks if a Parsi year is a leap year.
+/// With `raw`, prints `true` or `false` instead of `Yes`/`No`.
+pub fn handle_is_leap(year: i32, raw: bool) -> Result<()> {
+    check_supported_year(year)?;
+    let is_leap = ParsiDate::is_persian_leap_year(year);
+    if raw {
+        println!("{}", is_leap);
+    } else {
+        println!("{}", if is_leap { "Yes" } else { "No" });
+    }
+    Ok(())
+}
+
+/// Exit status of `is-workday --fail-if-holiday` on a Friday.
+const EXIT_FRIDAY: i32 = 2;
+/// Exit status of `is-workday --fail-if-holiday` on an official holiday.
+const EXIT_HOLIDAY: i32 = 3;
+
+/// Handles the `is-workday` command: Reports whether a date (default today) is a workday,
+/// a Friday, or an official holiday. Official holidays take precedence over Fridays.
+/// With `fail_if_holiday`, non-workdays exit with `EXIT_FRIDAY` or `EXIT_HOLIDAY`.
+pub fn handle_is_workday(
+    date_string: Option<String>,
+    fail_if_holiday: bool,
+    raw: bool,
+) -> Result<()> {
+    let date = match date_string {
+        Some(ds) => parse_input_datetime_or_date(&ds)
+            .with_context(|| format!("Failed to parse date string: {}", ds))?
+            .0
+            .date(),
+        None => clock::today()?,
+    };
+
+    let (kind, label, exit_code) = match business_days::classify(&date)? {
+        DayKind::Holiday => ("holiday", "Holiday", EXIT_HOLIDAY),
+        DayKind::Friday => ("friday", "Friday", EXIT_FRIDAY),
+        DayKind::Workday => ("workday", "Workday", 0),
+    };
+
+    if raw {
+        println!("{}", kind);
+    } else if exit_code == EXIT_HOLIDAY {
+        let titles: Vec<String> =
+            events::get_events_for_date(date.year(), date.month(), date.day())
+                .unwrap_or_default()
+                .into_iter()
+                .filter(|e| e.holiday)
+                .map(|e| e.title)
+                .collect();
+        println!("{} ({}): {}", label, date, titles.join("، "));
+    } else {
+        println!("{} ({})", label, date);
+    }
+
+    if fail_if_holiday && exit_code != 0 {
+        // Make sure the answer is written before the process ends.
+        std::io::stdout().flush()?;
+        std::process::exit(exit_code);
+    }
+    Ok(())
+}
+
+/// Handles the `academic` command: Places a date (default today) in the academic year
+/// and reports its term, week numbers, and the instructional days left in the term.
+pub fn handle_academic(
+    date_string: Option<String>,
+    term_start: &str,
+    second_term: &str,
+    classes_end: &str,
+    json: bool,
+) -> Result<()> {
+    let date = match date_string {
+        Some(ds) => parse_input_datetime_or_date(&ds)
+            .with_context(|| format!("Failed to parse date string: {}", ds))?
+            .0
+            .date(),
+        None => clock::today()?,
+    };
+    let calendar = academic::TermCalendar {
+        start: academic::MonthDay::parse(term_start)?,
+        second_term: academic::MonthDay::parse(second_term)?,
+        end: academic::MonthDay::parse(classes_end)?,
+    };
+    let info = academic::academic_info(&date, &calendar)?;
+
+    if json {
+        let output = serde_json::to_string_pretty(&info)
+            .context("Failed to serialize academic info to JSON")?;
+        println!("{}", output);
+        return Ok(());
+    }
+
+    println!(
+        "Academic Year {} ({} - {})",
+        info.academic_year, info.year_start, info.year_end
+    );
+    println!("-------------------------");
+    println!(" Date: {} ({})", info.date, info.weekday);
+    match (info.term, &info.term_start, &info.term_end) {
+        (Some(term), Some(first), Some(last)) => {
+            println!(" Term: {} ({} - {})", term, first, last)
+        }
+        _ => println!(" Term: Summer break"),
+    }
+    println!(" Academic Week: {}", info.academic_week);
+    if let Some(week) = info.term_week {
+        println!(" Term Week: {}", week);
         println!(
-            " Parsed Time: {:02}:{:02}:{:02}",
-            pdt.hour(),
-            pdt.minute(),
-            pdt.second()
+            " Instructional Days Left in Term: {}",
+            info.instructional_days_left
         );
     }
+    Ok(())
+}
 
-    // Calculated Info (handle errors gracefully)
-    match pdt.date().weekday() {
-        Ok(wd) => println!(" Weekday: {}", wd),
-        Err(e) => println!(" Weekday: Error ({})", e),
+/// Handles the `anniversary` command: Lists the next `count` yearly anniversaries of a
+/// Parsi date (or, with `gregorian`, a Gregorian date) from today on.
+pub fn handle_anniversary(date_string: String, count: usize, gregorian: bool) -> Result<()> {
+    let origin = if gregorian {
+        Origin::Gregorian(parse_gregorian_input(&date_string)?.0.date())
+    } else {
+        Origin::Parsi(
+            parse_input_datetime_or_date(&date_string)
+                .with_context(|| format!("Failed to parse date string: {}", date_string))?
+                .0
+                .date(),
+        )
+    };
+    let today = clock::today()?;
+    let anniversaries = anniversary::upcoming(&origin, &today, count)?;
+
+    let (heading, missing_day) = match origin {
+        Origin::Parsi(date) => {
+            let gregorian_date = date
+                .to_gregorian()
+                .map_err(|e| map_mitra_error(e, "converting to Gregorian"))?;
+            (
+                format!("{} ({})", date, gregorian_date.format("%Y-%m-%d")),
+                date.format("%d %B"),
+            )
+        }
+        Origin::Gregorian(date) => (
+            format!("{} ({})", date.format("%Y-%m-%d"), origin.parsi_date()?),
+            date.format("%B %d").to_string(),
+        ),
+    };
+    println!("Anniversaries of {}:", heading);
+    for item in anniversaries {
+        let weekday = item
+            .date
+            .weekday()
+            .map_err(|e| map_mitra_error(e, "getting weekday"))?;
+        let gregorian_date = item
+            .date
+            .to_gregorian()
+            .map_err(|e| map_mitra_error(e, "converting to Gregorian"))?;
+        let note = if item.shifted {
+            format!(" [no {} this year]", missing_day)
+        } else {
+            String::new()
+        };
+        println!(
+            "  {:>4}  {} {:<9} {}  {}{}",
+            item.years,
+            item.date,
+            weekday,
+            gregorian_date.format("%Y-%m-%d"),
+            humanize_day_span(item.days_until),
+            note
+        );
     }
-    match pdt.date().ordinal() {
-        Ok(ord) => println!(" Day of Year: {}", ord),
-        Err(e) => println!(" Day of Year: Error ({})", e),
+    Ok(())
+}
+
+/// Handles the `leave` command: Counts the leave days consumed by `start..=end` and
+/// prints a per-day breakdown, or the same data as JSON.
+pub fn handle_leave(start: String, end: String, exclude_thursdays: bool, json: bool) -> Result<()> {
+    let (start_pdt, _) = parse_input_datetime_or_date(&start)
+        .with_context(|| format!("Failed to parse leave start: {}", start))?;
+    let (end_pdt, _) = parse_input_datetime_or_date(&end)
+        .with_context(|| format!("Failed to parse leave end: {}", end))?;
+    let summary = leave::calculate(&start_pdt.date(), &end_pdt.date(), exclude_thursdays)?;
+
+    if json {
+        let output = serde_json::to_string_pretty(&summary)
+            .context("Failed to serialize leave summary to JSON")?;
+        println!("{}", output);
+        return Ok(());
     }
-    let days_in_mon = ParsiDate::days_in_month(pdt.year(), pdt.month());
-    if days_in_mon > 0 {
-        // Check if month was valid
-        println!(" Days in Current Month: {}", days_in_mon);
-    } else {
-        println!(" Days in Current Month: N/A (Invalid Month?)");
+
+    println!(
+        "Leave {} - {}: {} leave days ({} calendar days)",
+        summary.start, summary.end, summary.leave_days, summary.calendar_days
+    );
+    for day in &summary.days {
+        let status = match day.excluded {
+            None => "leave".to_string(),
+            Some(Exclusion::Friday) => "friday".to_string(),
+            Some(Exclusion::Thursday) => "thursday".to_string(),
+            Some(Exclusion::Holiday) => format!("holiday: {}", day.holidays.join("، ")),
+        };
+        println!("  {} {:<9} {}", day.date, day.weekday, status);
     }
-    let is_leap = ParsiDate::is_persian_leap_year(pdt.year());
-    println!(" Is Leap Year: {}", if is_leap { "Yes" } else { "No" });
+    Ok(())
+}
 
-    // Gregorian Conversion
-    match pdt.to_gregorian() {
-        Ok(g_ndt) => {
-            if was_datetime {
-                println!(
-                    " Gregorian Equivalent: {}",
-                    g_ndt.format("%Y-%m-%d %H:%M:%S")
-                );
-            } else {
-                println!(" Gregorian Equivalent: {}", g_ndt.format("%Y-%m-%d"));
-            }
+/// Handles the `worldclock` command: Shows an instant (default now) in each of `cities`.
+/// A given date/time is wall-clock time in the first city, so "when does Tehran's
+/// tomorrow start in Toronto" is `worldclock "1404/01/02 00:00:00" -c tehran,toronto`.
+pub fn handle_worldclock(
+    datetime_string: Option<String>,
+    cities: Vec<String>,
+    json: bool,
+) -> Result<()> {
+    let zones = cities
+        .iter()
+        .map(|city| timezone::resolve(city))
+        .collect::<Result<Vec<_>>>()?;
+    let Some(&reference) = zones.first() else {
+        bail!("Error: --cities must name at least one city or time zone.");
+    };
+    let instant = match datetime_string {
+        Some(ds) => {
+            let (pdt, _) = parse_input_datetime_or_date(&ds)
+                .with_context(|| format!("Failed to parse date/time: {}", ds))?;
+            let local = pdt
+                .to_gregorian()
+                .map_err(|e| map_mitra_error(e, "converting to Gregorian"))?;
+            timezone::localize(reference, local)?.with_timezone(&chrono::Utc)
         }
-        Err(e) => println!(" Gregorian Equivalent: Error ({})", e),
+        None => clock::utc_now()?,
+    };
+    let rows = worldclock::at(instant, &zones)?;
+
+    if json {
+        let output =
+            serde_json::to_string_pretty(&rows).context("Failed to serialize times to JSON")?;
+        println!("{}", output);
+        return Ok(());
+    }
+
+    let width = rows
+        .iter()
+        .map(|r| r.city.chars().count())
+        .max()
+        .unwrap_or(0);
+    for row in rows {
+        let day_note = match row.day_offset {
+            0 => String::new(),
+            d if d.abs() == 1 => format!(" ({:+} day)", d),
+            d => format!(" ({:+} days)", d),
+        };
+        println!(
+            "{:<width$}  {} {:<9} {}  UTC{}{}",
+            row.city,
+            row.parsi,
+            row.weekday,
+            row.gregorian,
+            row.offset,
+            day_note,
+            width = width
+        );
+    }
+    Ok(())
+}
+
+/// Handles the `info` command: Displays detailed information about a date/datetime.
+/// With `json`, prints the same information (plus the day's events) as one JSON document.
+/// With `field`, prints only that value; `raw` alone prints just the date.
+pub fn handle_info(
+    datetime_string: String,
+    json: bool,
+    field: Option<InfoField>,
+    raw: bool,
+) -> Result<()> {
+    if json && raw {
+        bail!("Error: --json and --raw cannot be used together.");
     }
 
-    // Boundary Dates (safe to call on the parsed date)
-    println!(" First Day of Month: {}", pdt.date().first_day_of_month());
-    println!(" Last Day of Month: {}", pdt.date().last_day_of_month());
-    println!(" First Day of Year: {}", pdt.date().first_day_of_year());
-    println!(" Last Day of Year: {}", pdt.date().last_day_of_year());
+    let (pdt, was_datetime) = parse_input_datetime_or_date(&datetime_string)?;
+    let info = DateInfo::new(&datetime_string, &pdt, was_datetime)?;
+
+    if raw || field.is_some() {
+        let Some(value) = info.field(field.unwrap_or(InfoField::Date)) else {
+            bail!("Error: '{}' has no time part.", datetime_string);
+        };
+        styled_println!("{}", value);
+        return Ok(());
+    }
+
+    if json {
+        let output =
+            serde_json::to_string_pretty(&info).context("Failed to serialize date info to JSON")?;
+        println!("{}", output);
+        return Ok(());
+    }
+
+    styled_println!("Input Parsi Date/Time: {}", info.input);
+    styled_println!("-------------------------");
+
+    // Basic Components
+    styled_println!(" Parsed Date: {}", info.date);
+    if let Some(time) = &info.time {
+        styled_println!(" Parsed Time: {}", time);
+    }
+
+    // Calculated Info
+    styled_println!(" Weekday: {}", info.weekday);
+    styled_println!(" Day of Year: {}", info.day_of_year);
+    styled_println!(" Week of Year: {}", info.week_of_year);
+    styled_println!(" Days in Current Month: {}", info.days_in_month);
+    styled_println!(
+        " Is Leap Year: {}",
+        if info.is_leap_year { "Yes" } else { "No" }
+    );
+    styled_println!(" Animal Year: {}", info.animal_year);
+    styled_println!(" Zodiac Sign: {}", info.zodiac_sign);
+    styled_println!(" Season: {} (Quarter {})", info.season, info.quarter);
+
+    // Gregorian Conversion
+    styled_println!(" Gregorian Equivalent: {}", info.gregorian);
+
+    // Boundary Dates
+    styled_println!(" First Day of Week: {}", info.first_day_of_week);
+    styled_println!(" Last Day of Week: {}", info.last_day_of_week);
+    styled_println!(" First Day of Quarter: {}", info.first_day_of_quarter);
+    styled_println!(" Last Day of Quarter: {}", info.last_day_of_quarter);
+    styled_println!(" First Day of Month: {}", info.first_day_of_month);
+    styled_println!(" Last Day of Month: {}", info.last_day_of_month);
+    styled_println!(" First Day of Year: {}", info.first_day_of_year);
+    styled_println!(" Last Day of Year: {}", info.last_day_of_year);
 
     Ok(())
 }
@@ -634,17 +1436,280 @@ pub fn handle_parse(input_string: String, pattern: String) -> Result<()> {
     Ok(())
 }
 
+/// Handles the `applet-feed` command: Prints the desktop applet data feed as JSON.
+pub fn handle_applet_feed(pretty: bool) -> Result<()> {
+    let today = clock::today().context("Failed to get today's date")?;
+    let feed = applet::build_feed(&today)?;
+    let output = if pretty {
+        serde_json::to_string_pretty(&feed)
+    } else {
+        serde_json::to_string(&feed)
+    }
+    .context("Failed to serialize applet feed to JSON")?;
+    println!("{}", output);
+    Ok(())
+}
+
+/// Handles the `statusbar` command: Prints one status line, or a new line every `interval` seconds.
+pub fn handle_statusbar(format: &str, json: bool, interval: Option<u64>) -> Result<()> {
+    if interval == Some(0) {
+        bail!("Error: --interval must be at least 1 second.");
+    }
+
+    loop {
+        // Re-read today's date on every refresh so the line rolls over at midnight.
+        let today = clock::today().context("Failed to get today's date")?;
+        let line = if json {
+            serde_json::to_string(&statusbar::waybar_output(&today, format)?)
+                .context("Failed to serialize status line to JSON")?
+        } else {
+            statusbar::render_line(&today, format)?
+        };
+        println!("{}", line);
+
+        let Some(seconds) = interval else {
+            return Ok(());
+        };
+        std::thread::sleep(std::time::Duration::from_secs(seconds));
+    }
+}
+
+/// Handles the `schedule` command: With `next`, prints the upcoming run times of the
+/// schedule; otherwise runs `exec` at each of them until interrupted.
+pub fn handle_schedule(at: String, exec: Option<String>, next: Option<usize>) -> Result<()> {
+    let schedule = Schedule::parse(&at)?;
+
+    if let Some(count) = next {
+        let mut after = clock::now()?;
+        for index in 0..count {
+            let Some(time) = schedule.next_after(&after)? else {
+                if index == 0 {
+                    bail!("Error: The schedule '{}' has no upcoming runs.", at);
+                }
+                break;
+            };
+            println!("{}", time);
+            after = time;
+        }
+        return Ok(());
+    }
+
+    let command = exec.context("Error: --exec is required unless --next is given.")?;
+    schedule::run_daemon(&schedule, &command)
+}
+
+/// Handles the `rpc` command: Serves JSON-RPC requests on stdin/stdout until EOF.
+pub fn handle_rpc() -> Result<()> {
+    let stdin = std::io::stdin();
+    let stdout = std::io::stdout();
+    rpc::serve(stdin.lock(), stdout.lock())
+}
+
+/// Handles the `pick` command: Prints one selectable line per day (or per event) of a month,
+/// each starting with its date, for dmenu/rofi/Alfred. With `--select`, prints the details
+/// of the date that a chosen line starts with.
+pub fn handle_pick(
+    month_opt: Option<u32>,
+    year_opt: Option<i32>,
+    events_only: bool,
+    select: Option<String>,
+    raw: bool,
+) -> Result<()> {
+    if let Some(line) = select {
+        let Some(date_str) = line.split_whitespace().next() else {
+            bail!("Error: The selected line is empty.");
+        };
+        return handle_info(date_str.to_string(), false, None, raw);
+    }
+
+    let today = clock::today().context("Failed to get today's date")?;
+    let year = year_opt.unwrap_or(today.year());
+    let month = month_opt.unwrap_or(today.month());
+    check_supported_year(year)?;
+    let first = ParsiDate::new(year, month, 1)
+        .map_err(|e| map_mitra_error(e, &format!("creating date {}-{}-1", year, month)))?;
+
+    let mut date = first;
+    while date <= first.last_day_of_month() {
+        let day_events =
+            events::get_events_for_date(date.year(), date.month(), date.day()).unwrap_or_default();
+        if events_only {
+            for event in &day_events {
+                let marker = if event.holiday { "[تعطیل] " } else { "" };
+                println!("{}  {}{}", date, marker, event.title);
+            }
+        } else {
+            let weekday = date
+                .weekday()
+                .map_err(|e| map_mitra_error(e, "getting weekday"))?;
+            let mut line = format!("{}  {}", date, weekday);
+            if date == today {
+                line.push_str(" (امروز)");
+            }
+            if let Some(event) = day_events.iter().find(|e| e.holiday).or(day_events.first()) {
+                let marker = if event.holiday { "[تعطیل] " } else { "" };
+                line.push_str(&format!("  {}{}", marker, event.title));
+            }
+            println!("{}", line);
+        }
+        date = date
+            .add_days(1)
+            .map_err(|e| map_mitra_error(e, "iterating over month"))?;
+    }
+    Ok(())
+}
+
+/// Handles the `batch` command: Streams stdin through a conversion, one line at a time.
+pub fn handle_batch(operation: BatchOperation, skip_errors: bool) -> Result<()> {
+    let stdin = std::io::stdin();
+    let stdout = std::io::BufWriter::new(std::io::stdout().lock());
+    let summary = batch::run(operation, stdin.lock(), stdout, skip_errors)?;
+
+    log::info!(
+        "Converted {} line(s), {} failed",
+        summary.converted,
+        summary.failed
+    );
+    if summary.failed > 0 {
+        log::warn!("{} input line(s) could not be converted", summary.failed);
+    }
+    Ok(())
+}
+
+/// Handles the `doctor` command: Runs the self-checks and exits with an error if any fail.
+pub fn handle_doctor() -> Result<()> {
+    let checks = doctor::run_checks();
+    for check in &checks {
+        println!(
+            "[{:<4}] {}: {}",
+            check.status.label(),
+            check.name,
+            check.summary
+        );
+        for detail in &check.details {
+            println!("         - {}", detail);
+        }
+    }
+
+    let failed = checks
+        .iter()
+        .filter(|c| c.status == doctor::Status::Fail)
+        .count();
+    if failed > 0 {
+        bail!("Error: {} check(s) failed.", failed);
+    }
+    Ok(())
+}
+
+/// Handles the `zodiac` command: Prints the traditional animal year name for a Parsi year.
+pub fn handle_zodiac(year_opt: Option<i32>) -> Result<()> {
+    let year = match year_opt {
+        Some(y) => y,
+        None => clock::today().context("Failed to get today's date")?.year(),
+    };
+    check_supported_year(year)?;
+    let (_, english) = zodiac::animal_of_year(year);
+    println!("{}: {} ({})", year, zodiac::animal_year_name(year), english);
+    Ok(())
+}
+
+/// Handles the `sign` command: Prints the solar zodiac sign of a date (default today)
+/// and the Parsi and Gregorian dates it spans, which are those of the date's month.
+pub fn handle_sign(date_string: Option<String>) -> Result<()> {
+    let date = match date_string {
+        Some(ds) => parse_input_datetime_or_date(&ds)
+            .with_context(|| format!("Failed to parse date string: {}", ds))?
+            .0
+            .date(),
+        None => clock::today()?,
+    };
+    let (first, last) = (date.first_day_of_month(), date.last_day_of_month());
+    let gregorian = |d: ParsiDate| {
+        d.to_gregorian()
+            .map(|g| g.format("%Y-%m-%d").to_string())
+            .map_err(|e| map_mitra_error(e, "converting to Gregorian"))
+    };
+    let (_, english) = zodiac::sign_of_month(date.month());
+    println!(
+        "{} ({}): {} - {} ({} - {})",
+        zodiac::sign_name(date.month()),
+        english,
+        first,
+        last,
+        gregorian(first)?,
+        gregorian(last)?
+    );
+    Ok(())
+}
+
+/// Handles the `map` command: Prints each month of a Parsi year with its Gregorian start/end dates.
+pub fn handle_map(year_opt: Option<i32>) -> Result<()> {
+    let year = match year_opt {
+        Some(y) => y,
+        None => clock::today().context("Failed to get today's date")?.year(),
+    };
+
+    // Validate the year before printing anything.
+    check_supported_year(year)?;
+
+    println!("Month map for {}:", year);
+    println!(" #  Month      Days  Gregorian Start  Gregorian End");
+    for month in 1..=12 {
+        let first = ParsiDate::new(year, month, 1)
+            .map_err(|e| map_mitra_error(e, &format!("creating date {}-{}-1", year, month)))?;
+        let last = first.last_day_of_month();
+
+        let g_first = first
+            .to_gregorian()
+            .map_err(|e| map_mitra_error(e, "converting month start to Gregorian"))?;
+        let g_last = last
+            .to_gregorian()
+            .map_err(|e| map_mitra_error(e, "converting month end to Gregorian"))?;
+
+        println!(
+            "{:>2}  {:<10} {:>4}  {:<15}  {}",
+            month,
+            first.format("%B"),
+            last.day(),
+            g_first.format("%Y-%m-%d"),
+            g_last.format("%Y-%m-%d")
+        );
+    }
+    Ok(())
+}
+
 /// Handles the `events` command: Lists events for a specific date.
-pub fn handle_events(date_string: String) -> Result<()> {
-    // Parse the input date string (ignore time part)
-    let (pdt, _) = parse_input_datetime_or_date(&date_string)
-        .with_context(|| format!("Failed to parse date string: {}", date_string))?;
-
-    let month = pdt.month();
-    let day = pdt.day();
-    let year = pdt.year();
+/// With several dates, each date gets its own group (including the year), separated by blank lines.
+pub fn handle_events(date_strings: Vec<String>) -> Result<()> {
+    // Parse all inputs up front so a typo doesn't leave partial output behind.
+    let dates = date_strings
+        .iter()
+        .map(|date_string| {
+            parse_input_datetime_or_date(date_string)
+                .map(|(pdt, _)| pdt.date())
+                .with_context(|| format!("Failed to parse date string: {}", date_string))
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    // Include the year in headers when groups could otherwise look alike.
+    let header_pattern = if dates.len() > 1 { "%d %B %Y" } else { "%d %B" };
+    for (index, date) in dates.iter().enumerate() {
+        if index > 0 {
+            println!();
+        }
+        print_events_for_date(date, header_pattern);
+    }
+
+    Ok(())
+}
+
+/// Prints the events block for a single date, using `header_pattern` to format the heading.
+fn print_events_for_date(date: &ParsiDate, header_pattern: &str) {
+    let month = date.month();
+    let day = date.day();
+    let year = date.year();
     // Format the date for display (e.g., "6 مرداد")
-    let display_date = pdt.format("%d %B"); // Or "%A %d %B" for weekday
+    let display_date = date.format(header_pattern); // Or "%A %d %B" for weekday
 
     println!("Events for {}:", display_date);
 
@@ -668,6 +1733,285 @@ pub fn handle_events(date_string: String) -> Result<()> {
         // If the date key wasn't found in the map
         println!("  - No events found.");
     }
+}
+
+/// Handles the `export` command: Writes one record per day of a Parsi year to stdout.
+pub fn handle_export(
+    year_opt: Option<i32>,
+    year_range: Option<String>,
+    format: ExportFormat,
+) -> Result<()> {
+    let (start_year, end_year) = match (year_opt, year_range) {
+        (_, Some(range)) => parse_year_range(&range)?,
+        (Some(y), None) => (y, y),
+        (None, None) => {
+            let year = clock::today().context("Failed to get today's date")?.year();
+            (year, year)
+        }
+    };
+
+    check_supported_year(start_year)?;
+    check_supported_year(end_year)?;
+
+    let mut records = Vec::new();
+    for year in start_year..=end_year {
+        records.extend(export::year_records(year)?);
+    }
+    let mut out = std::io::stdout().lock();
+    match format {
+        ExportFormat::Csv => export::write_csv(&records, &mut out)?,
+        ExportFormat::OrgDiary => export::write_org(&records, false, &mut out)?,
+        ExportFormat::OrgAgenda => export::write_org(&records, true, &mut out)?,
+        ExportFormat::Sql => export::write_sql(&records, &mut out)?,
+        ExportFormat::Parquet | ExportFormat::Xlsx if out.is_terminal() => {
+            bail!(
+                "Error: This export format is binary; redirect it to a file (e.g., > 1404.xlsx)."
+            );
+        }
+        ExportFormat::Parquet => write_parquet_export(&records, &mut out)?,
+        ExportFormat::Xlsx => write_xlsx_export(&records, &mut out)?,
+    }
+    Ok(())
+}
+
+/// Writes the XLSX export.
+#[cfg(feature = "export")]
+fn write_xlsx_export<W: Write>(records: &[export::DayRecord], out: &mut W) -> Result<()> {
+    out.write_all(&export::xlsx_workbook(records)?)
+        .context("Failed to write XLSX output")
+}
+
+#[cfg(not(feature = "export"))]
+fn write_xlsx_export<W: Write>(_records: &[export::DayRecord], _out: &mut W) -> Result<()> {
+    bail!(
+        "Error: XLSX export is not available in this build. Rebuild mitra with `--features export`."
+    )
+}
+
+/// Writes the Parquet export. The Parquet writer needs a `Send` sink, so the file is
+/// built in memory and then copied to `out`.
+#[cfg(feature = "arrow")]
+fn write_parquet_export<W: Write>(records: &[export::DayRecord], out: &mut W) -> Result<()> {
+    let mut buffer = Vec::new();
+    export::write_parquet(records, &mut buffer)?;
+    out.write_all(&buffer)
+        .context("Failed to write Parquet output")
+}
+
+#[cfg(not(feature = "arrow"))]
+fn write_parquet_export<W: Write>(_records: &[export::DayRecord], _out: &mut W) -> Result<()> {
+    bail!(
+        "Error: Parquet export is not available in this build. Rebuild mitra with `--features arrow`."
+    )
+}
+
+/// Parses an inclusive year range of the form `START..END` (e.g., "1390..1420").
+fn parse_year_range(range: &str) -> Result<(i32, i32)> {
+    let Some((start, end)) = range.trim().split_once("..") else {
+        bail!(
+            "Error: Invalid year range '{}'. Expected START..END (e.g., 1390..1420).",
+            range
+        );
+    };
+    let start: i32 = start
+        .trim()
+        .parse()
+        .with_context(|| format!("Invalid start year in range '{}'", range))?;
+    let end: i32 = end
+        .trim()
+        .parse()
+        .with_context(|| format!("Invalid end year in range '{}'", range))?;
+    if start > end {
+        bail!(
+            "Error: The start year {} is after the end year {} in the range.",
+            start,
+            end
+        );
+    }
+    Ok((start, end))
+}
+
+/// How many days ahead `feed` covers when neither a year nor `--days` is given.
+const FEED_DEFAULT_DAYS: u32 = 365;
+
+/// Handles the `feed` command: Writes a feed of days with events to stdout.
+/// Covers the given year, or the next `days` (365) days when no year is given.
+pub fn handle_feed(year_opt: Option<i32>, days: Option<u32>, format: FeedFormat) -> Result<()> {
+    let (title, records) = match year_opt {
+        Some(year) => {
+            check_supported_year(year)?;
+            (
+                format!("مناسبت‌های سال {}", year),
+                export::year_records(year)?,
+            )
+        }
+        None => {
+            let days = days.unwrap_or(FEED_DEFAULT_DAYS);
+            if days == 0 {
+                bail!("Error: --days must be at least 1.");
+            }
+            let today = clock::today().context("Failed to get today's date")?;
+            let last = today
+                .add_days(i64::from(days) - 1)
+                .map_err(|e| map_mitra_error(e, "calculating feed range"))?;
+            (
+                "مناسبت‌های پیش رو".to_string(),
+                export::range_records(&today, &last)?,
+            )
+        }
+    };
+
+    let mut out = std::io::stdout().lock();
+    match format {
+        FeedFormat::Atom => feed::write_atom(&title, &records, &mut out)?,
+        FeedFormat::Rss => feed::write_rss(&title, &records, &mut out)?,
+    }
+    Ok(())
+}
+
+/// Handles the `onthisday` command: Lists historical occasions for a month/day.
+pub fn handle_on_this_day(date_string: Option<String>, category: Option<String>) -> Result<()> {
+    let date = match date_string {
+        Some(ds) => parse_input_datetime_or_date(&ds)
+            .with_context(|| format!("Failed to parse date string: {}", ds))?
+            .0
+            .date(),
+        None => clock::today().context("Failed to get today's date")?,
+    };
+
+    print_occasions(&date, category.as_deref());
+    Ok(())
+}
+
+/// Handles the `today` command: Prints today's Parsi date and, with `occasions`, the
+/// historical occasions on this month/day (as `onthisday` does), optionally filtered by
+/// `category`.
+pub fn handle_today(occasions: bool, category: Option<String>) -> Result<()> {
+    let today = clock::today().context("Failed to get today's date")?;
+    styled_println!("{}", today.format("%A %d %B %Y"));
+    if occasions {
+        print_occasions(&today, category.as_deref());
+    }
+    Ok(())
+}
+
+/// Prints the occasions on `date`'s month/day under an "On this day" heading.
+fn print_occasions(date: &ParsiDate, category: Option<&str>) {
+    println!("On this day ({}):", date.format("%d %B"));
+
+    let occasions = events::get_occasions_for_date(date.month(), date.day(), category);
+    if occasions.is_empty() {
+        println!("  - No occasions found.");
+    }
+    for occasion in occasions {
+        println!(
+            "  {} - {} [{}]",
+            occasion.year, occasion.title, occasion.category
+        );
+    }
+}
+
+/// Handles the `fiscal-deadlines` command: Lists recurring fiscal deadlines for a year,
+/// or those due within the next `upcoming` days.
+pub fn handle_fiscal_deadlines(
+    year_opt: Option<i32>,
+    upcoming: Option<u32>,
+    rules_path: Option<std::path::PathBuf>,
+    json: bool,
+) -> Result<()> {
+    let rules = match rules_path {
+        Some(path) => {
+            let content = std::fs::read_to_string(&path)
+                .with_context(|| format!("Failed to read rules file: {}", path.display()))?;
+            fiscal::parse_rules(&content)
+                .with_context(|| format!("Failed to load rules file: {}", path.display()))?
+        }
+        None => fiscal::builtin_rules()?,
+    };
+
+    let (heading, first, last) = match upcoming {
+        Some(days) => {
+            let today = clock::today()?;
+            let last = today
+                .add_days(i64::from(days))
+                .map_err(|e| map_mitra_error(e, "calculating reminder window"))?;
+            (
+                format!("Fiscal deadlines in the next {} days:", days),
+                today,
+                last,
+            )
+        }
+        None => {
+            let year = match year_opt {
+                Some(year) => year,
+                None => clock::today()?.year(),
+            };
+            check_supported_year(year)?;
+            let first = ParsiDate::new(year, 1, 1)
+                .map_err(|e| map_mitra_error(e, "creating start of year"))?;
+            let last = ParsiDate::new(year, 12, ParsiDate::days_in_month(year, 12))
+                .map_err(|e| map_mitra_error(e, "creating end of year"))?;
+            (format!("Fiscal deadlines {}:", year), first, last)
+        }
+    };
+
+    let deadlines = fiscal::deadlines_between(&rules, &first, &last)?;
+    if json {
+        let output = serde_json::to_string_pretty(&deadlines)
+            .context("Failed to serialize deadlines to JSON")?;
+        println!("{}", output);
+        return Ok(());
+    }
 
+    println!("{}", heading);
+    if deadlines.is_empty() {
+        println!("  - No deadlines found.");
+    }
+    for deadline in deadlines {
+        let moved = deadline
+            .moved_from
+            .map(|nominal| format!(" [moved from {}]", nominal))
+            .unwrap_or_default();
+        println!(
+            "  {} {:<9} {} ({}){}",
+            deadline.date, deadline.weekday, deadline.title, deadline.period, moved
+        );
+    }
+    Ok(())
+}
+
+/// Handles the `nowruz` command: Prints the moment of Sāl Tahvil for `year_opt` (or the
+/// next one) in Tehran and UTC, with the time left until it or since it passed.
+pub fn handle_nowruz(year_opt: Option<i32>) -> Result<()> {
+    let now = clock::utc_now()?;
+    let (year, moment) = match year_opt {
+        Some(year) => (year, astronomy::get_nowruz_moment(year)?),
+        None => {
+            // Start from the local year: west of Tehran it can still be the previous one.
+            let mut year = clock::today()?.year();
+            loop {
+                let moment = astronomy::get_nowruz_moment(year)?;
+                if moment.to_utc() > now {
+                    break (year, moment);
+                }
+                year += 1;
+            }
+        }
+    };
+
+    let utc = moment.to_utc();
+    let to_parsi = |instant: chrono::DateTime<chrono::Utc>| {
+        ParsiDateTime::from_gregorian(instant.naive_utc())
+            .map_err(|e| map_mitra_error(e, "converting to Parsi"))
+    };
+    let breakdown = duration::get_duration_breakdown(&to_parsi(now)?, &to_parsi(utc)?)?;
+
+    println!("Sāl Tahvil {}: {}", year, moment);
+    println!("UTC: {}", utc.format("%Y-%m-%d %H:%M:%S"));
+    if utc > now {
+        println!("Time remaining: {}", breakdown);
+    } else {
+        println!("Time since: {}", breakdown);
+    }
     Ok(())
 }
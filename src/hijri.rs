@@ -0,0 +1,153 @@
+//  ~/src/hijri.rs
+//
+//  * Copyright (C) 2024–2025 Parsicore <parsicore.dev@gmail.com>
+//  * Package : mitra
+//  * License : Apache-2.0
+//  * Version : 2.3.0
+//  * URL     : https://github.com/parsicore/Mitra
+//  * Sign: mitra-20250419-bd5fbe728fa2-5836b45f25d83501625cc5529193d5f0
+//
+//! The Islamic (Hijri Qamari) calendar in its tabular (arithmetical) form: months
+//! alternate between 30 and 29 days, and 11 years in each 30-year cycle add a day to
+//! Dhu al-Hijjah. Conversion goes through the Julian Day Number to chrono's Gregorian
+//! `NaiveDate`, and from there to Parsi dates like any other Gregorian input.
+//!
+//! The official calendars of Iran and Saudi Arabia start months on moon sightings or
+//! astronomical criteria, so a tabular date can differ from them by a day or two.
+
+use crate::utils::{check_strict_shape, is_strict_parsing, to_ascii_digits};
+use anyhow::{Context, Result, bail};
+use chrono::{Datelike, NaiveDate, NaiveTime};
+use std::fmt;
+
+/// Julian Day Number of 1 Muharram 1 AH (16 July 622, Julian), the civil epoch.
+const JDN_EPOCH: i64 = 1_948_440;
+
+/// Julian Day Number of 0001-01-01 (proleptic Gregorian), day 1 of chrono's CE count.
+const JDN_CE_DAY_ONE: i64 = 1_721_426;
+
+/// A date in the tabular Hijri calendar, from 1 Muharram 1 AH.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct HijriDate {
+    year: i32,
+    month: u32,
+    day: u32,
+}
+
+impl HijriDate {
+    /// Creates a Hijri date, validating it against tabular month lengths.
+    pub fn new(year: i32, month: u32, day: u32) -> Result<HijriDate> {
+        if year < 1 {
+            bail!("Error: Hijri year {} is out of range (1 or later).", year);
+        }
+        if !(1..=12).contains(&month) || day < 1 || day > days_in_month(year, month) {
+            bail!(
+                "Error: {:04}/{:02}/{:02} is not a valid Hijri date.",
+                year,
+                month,
+                day
+            );
+        }
+        Ok(HijriDate { year, month, day })
+    }
+
+    /// The Hijri year.
+    pub fn year(self) -> i32 {
+        self.year
+    }
+
+    /// Converts to the equivalent (proleptic) Gregorian date.
+    pub fn to_gregorian(self) -> Result<NaiveDate> {
+        let jdn = day_number(self.year, self.month, self.day);
+        i32::try_from(jdn - JDN_CE_DAY_ONE + 1)
+            .ok()
+            .and_then(NaiveDate::from_num_days_from_ce_opt)
+            .with_context(|| format!("Error: Hijri date {} is out of range.", self))
+    }
+
+    /// Converts a (proleptic) Gregorian date to the Hijri calendar.
+    pub fn from_gregorian(date: NaiveDate) -> Result<HijriDate> {
+        let jdn = date.num_days_from_ce() as i64 - 1 + JDN_CE_DAY_ONE;
+        if jdn < JDN_EPOCH {
+            bail!(
+                "Error: {} is before the start of the Hijri calendar (0622-07-19).",
+                date.format("%Y-%m-%d")
+            );
+        }
+        let year = ((30 * (jdn - JDN_EPOCH) + 10646) / 10631) as i32;
+        let mut month = 12;
+        while month > 1 && day_number(year, month, 1) > jdn {
+            month -= 1;
+        }
+        let day = (jdn - day_number(year, month, 1) + 1) as u32;
+        HijriDate::new(year, month, day)
+    }
+}
+
+impl fmt::Display for HijriDate {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:04}/{:02}/{:02}", self.year, self.month, self.day)
+    }
+}
+
+/// Whether `year` is one of the 11 leap years of its 30-year cycle
+/// (2, 5, 7, 10, 13, 16, 18, 21, 24, 26, 29).
+fn is_leap_year(year: i32) -> bool {
+    (14 + 11 * i64::from(year)).rem_euclid(30) < 11
+}
+
+/// Number of days in a month of the tabular calendar: 30 in odd months, 29 in even
+/// ones, and 30 in Dhu al-Hijjah of a leap year.
+pub fn days_in_month(year: i32, month: u32) -> u32 {
+    match month {
+        12 if is_leap_year(year) => 30,
+        m if m % 2 == 1 => 30,
+        _ => 29,
+    }
+}
+
+/// The Julian Day Number of a Hijri date (not validated).
+fn day_number(year: i32, month: u32, day: u32) -> i64 {
+    let year = i64::from(year);
+    let month = i64::from(month);
+    // Days in the months before `month`: 30 and 29 alternately, i.e. ceil(29.5 * (month - 1)).
+    let months_before = (59 * (month - 1) + 1) / 2;
+    i64::from(day) + months_before + (year - 1) * 354 + (3 + 11 * year) / 30 + JDN_EPOCH - 1
+}
+
+/// Parses a Hijri date (`YYYY/MM/DD` or `YYYY-MM-DD`), optionally followed by a time
+/// (`HH:MM:SS`, after a space or `T`). Returns the date, the time (midnight for plain
+/// dates), and whether the input included a time.
+///
+/// In strict mode, the input must pass `check_strict_shape` like every other date input.
+pub fn parse_input(input: &str) -> Result<(HijriDate, NaiveTime, bool)> {
+    if is_strict_parsing() {
+        check_strict_shape(input)?;
+    }
+    let normalized = to_ascii_digits(input.trim());
+    let (date_part, time_part) = match normalized.split_once([' ', 'T']) {
+        Some((date, time)) => (date, Some(time.trim())),
+        None => (normalized.as_str(), None),
+    };
+    let invalid = || {
+        format!(
+            "Could not parse Hijri date/datetime '{}'. Use formats like YYYY/MM/DD or YYYY/MM/DD HH:MM:SS",
+            input.trim()
+        )
+    };
+
+    let parts: Vec<&str> = date_part.split(['-', '/']).collect();
+    let [year, month, day] = parts[..] else {
+        bail!(invalid());
+    };
+    let date = HijriDate::new(
+        year.parse().with_context(invalid)?,
+        month.parse().with_context(invalid)?,
+        day.parse().with_context(invalid)?,
+    )?;
+    let time = match time_part {
+        Some(time) => NaiveTime::parse_from_str(time, "%H:%M:%S").with_context(invalid)?,
+        None => NaiveTime::MIN,
+    };
+    Ok((date, time, time_part.is_some()))
+}
@@ -0,0 +1,323 @@
+//  ~/src/ics.rs
+//
+//  * Copyright (C) 2024–2025 Parsicore <parsicore.dev@gmail.com>
+//  * Package : mitra
+//  * License : Apache-2.0
+//  * Version : 2.3.0
+//  * URL     : https://github.com/parsicore/Mitra
+//  * Sign: mitra-20250419-bd5fbe728fa2-5836b45f25d83501625cc5529193d5f0
+//
+//! iCalendar (RFC 5545) export and import of recurring events on Parsi dates.
+//!
+//! A Parsi rule cannot be written as a Gregorian `RRULE` (the months do not line up), so
+//! export writes the series as `DTSTART` plus an `RDATE` list that any calendar app
+//! shows on the right days, and keeps the rule itself in `X-MITRA-RRULE`.
+//!
+//! Import reads each `VEVENT`'s `DTSTART`, `X-MITRA-RRULE` or `RRULE`, `RDATE`, and
+//! `EXDATE`. A Gregorian `RRULE` is evaluated by `RecurrenceRule` only where it means the
+//! same on both calendars: `DAILY` and `WEEKLY` rules without `BYMONTH` or `BYMONTHDAY`.
+//! `MONTHLY` and `YEARLY` rules, and weekly rules whose result depends on the week start,
+//! are rejected rather than moved to the wrong days. Only dates are considered; UTC
+//! times (`...Z`) are converted to the given zone first.
+
+use crate::recurrence::{Frequency, RecurrenceRule};
+use crate::utils::{map_mitra_error, to_ascii_digits};
+use crate::weekday;
+use anyhow::{Context, Result, bail};
+use chrono::{DateTime, NaiveDate, NaiveDateTime, Utc};
+use chrono_tz::Tz;
+use parsidate::ParsiDate;
+use std::io::Write;
+
+/// Maximum line length in octets before folding (RFC 5545 section 3.1).
+const MAX_LINE_OCTETS: usize = 75;
+
+/// A `VEVENT` read from a calendar, on Parsi dates.
+#[derive(Debug, Clone)]
+pub struct Event {
+    pub summary: String,
+    pub start: ParsiDate,
+    rule: Option<RecurrenceRule>,
+    rdates: Vec<ParsiDate>,
+    exdates: Vec<ParsiDate>,
+}
+
+impl Event {
+    /// The event's dates from `from` through `to`, inclusive: `DTSTART`, the rule's
+    /// occurrences, and `RDATE`s, without `EXDATE`s, in order.
+    pub fn occurrences(&self, from: ParsiDate, to: ParsiDate) -> Result<Vec<ParsiDate>> {
+        let mut dates = vec![self.start];
+        if let Some(rule) = &self.rule
+            && self.start <= to
+        {
+            dates.extend(rule.occurrences(self.start, to)?);
+        }
+        dates.extend(&self.rdates);
+        dates.retain(|date| (from..=to).contains(date) && !self.exdates.contains(date));
+        dates.sort();
+        dates.dedup();
+        Ok(dates)
+    }
+}
+
+/// Writes a calendar with one event for the dates of `rule`, whose first date becomes
+/// `DTSTART` and the rest `RDATE`s. The rule is kept in `X-MITRA-RRULE`, ending at the
+/// last date.
+pub fn write_series<W: Write>(
+    out: &mut W,
+    rule: &str,
+    dates: &[ParsiDate],
+    summary: Option<&str>,
+    stamp: DateTime<Utc>,
+) -> Result<()> {
+    let Some((first, rest)) = dates.split_first() else {
+        bail!("Error: The rule has no dates to export.");
+    };
+    let first_day = gregorian(first)?.format("%Y%m%d").to_string();
+    let normalized = to_ascii_digits(rule.trim()).to_uppercase();
+    let body = normalized.strip_prefix("RRULE:").unwrap_or(&normalized);
+    let uid: String = body
+        .chars()
+        .map(|c| if c.is_ascii_alphanumeric() { c } else { '-' })
+        .collect();
+    // Bound the rule by the last exported date, so importing it gives the same dates.
+    let mut rule_parts: Vec<String> = body
+        .split(';')
+        .filter(|part| !part.is_empty() && !part.starts_with("UNTIL="))
+        .map(str::to_string)
+        .collect();
+    rule_parts.push(format!("UNTIL={}", dates[dates.len() - 1]));
+
+    let mut lines = vec![
+        "BEGIN:VCALENDAR".to_string(),
+        "VERSION:2.0".to_string(),
+        "PRODID:-//parsicore//mitra//EN".to_string(),
+        "BEGIN:VEVENT".to_string(),
+        format!("UID:{}-{}@mitra", first_day, uid),
+        format!("DTSTAMP:{}", stamp.format("%Y%m%dT%H%M%SZ")),
+        format!("DTSTART;VALUE=DATE:{}", first_day),
+    ];
+    if let Some(summary) = summary {
+        lines.push(format!("SUMMARY:{}", escape_text(summary)));
+    }
+    lines.push(format!("X-MITRA-RRULE:{}", rule_parts.join(";")));
+    if !rest.is_empty() {
+        let days = rest
+            .iter()
+            .map(|date| Ok(gregorian(date)?.format("%Y%m%d").to_string()))
+            .collect::<Result<Vec<_>>>()?;
+        lines.push(format!("RDATE;VALUE=DATE:{}", days.join(",")));
+    }
+    lines.extend(["END:VEVENT".to_string(), "END:VCALENDAR".to_string()]);
+
+    for line in lines {
+        write!(out, "{}", fold_line(&line)).context("Failed to write calendar")?;
+    }
+    Ok(())
+}
+
+/// Reads the `VEVENT`s of a calendar. UTC times are taken as dates in `tz`.
+pub fn parse_calendar(input: &str, tz: Tz) -> Result<Vec<Event>> {
+    let mut events = Vec::new();
+    let mut current: Option<EventBuilder> = None;
+    for (line_number, line) in unfold_lines(input).into_iter().enumerate() {
+        let Some((head, value)) = line.split_once(':') else {
+            continue;
+        };
+        let mut params = head.split(';');
+        let name = params.next().unwrap_or_default().to_uppercase();
+        let is_date_only = params.any(|param| param.eq_ignore_ascii_case("VALUE=DATE"));
+        let context = || format!("Failed to read calendar line {}: {}", line_number + 1, line);
+
+        match (name.as_str(), current.as_mut()) {
+            ("BEGIN", None) if value.eq_ignore_ascii_case("VEVENT") => {
+                current = Some(EventBuilder::default())
+            }
+            ("END", Some(_)) if value.eq_ignore_ascii_case("VEVENT") => {
+                events.push(current.take().expect("inside VEVENT").build(tz)?)
+            }
+            ("SUMMARY", Some(event)) => event.summary = unescape_text(value),
+            ("DTSTART", Some(event)) => {
+                let start = parse_date_value(value, is_date_only, tz).with_context(context)?;
+                event.start = Some(start);
+            }
+            ("RRULE", Some(event)) => event.rrule = Some(value.to_string()),
+            ("X-MITRA-RRULE", Some(event)) => event.parsi_rule = Some(value.to_string()),
+            ("RDATE", Some(event)) => event
+                .rdates
+                .extend(parse_date_list(value, is_date_only, tz).with_context(context)?),
+            ("EXDATE", Some(event)) => event
+                .exdates
+                .extend(parse_date_list(value, is_date_only, tz).with_context(context)?),
+            _ => {}
+        }
+    }
+    if current.is_some() {
+        bail!("Error: The calendar ends inside a VEVENT (missing END:VEVENT).");
+    }
+    Ok(events)
+}
+
+/// Properties collected while reading a `VEVENT`.
+#[derive(Debug, Default)]
+struct EventBuilder {
+    summary: String,
+    start: Option<ParsiDate>,
+    rrule: Option<String>,
+    parsi_rule: Option<String>,
+    rdates: Vec<ParsiDate>,
+    exdates: Vec<ParsiDate>,
+}
+
+impl EventBuilder {
+    fn build(self, tz: Tz) -> Result<Event> {
+        let start = self
+            .start
+            .with_context(|| format!("Error: The event '{}' has no DTSTART.", self.summary))?;
+        let rule = match (self.parsi_rule, self.rrule) {
+            (Some(rule), _) => Some(RecurrenceRule::parse(&rule)?),
+            (None, Some(rule)) => Some(
+                gregorian_rule(&rule, start, tz)
+                    .with_context(|| format!("Failed to read the RRULE of '{}'", self.summary))?,
+            ),
+            (None, None) => None,
+        };
+        Ok(Event {
+            summary: self.summary,
+            start,
+            rule,
+            rdates: self.rdates,
+            exdates: self.exdates,
+        })
+    }
+}
+
+/// Translates a Gregorian `RRULE` that means the same on the Parsi calendar.
+fn gregorian_rule(rrule: &str, start: ParsiDate, tz: Tz) -> Result<RecurrenceRule> {
+    let mut parts = Vec::new();
+    for part in rrule.split(';').filter(|part| !part.is_empty()) {
+        match part.split_once('=') {
+            Some((name, value)) if name.eq_ignore_ascii_case("UNTIL") => {
+                let until = parse_date_value(value, false, tz)?;
+                parts.push(format!("UNTIL={}", until));
+            }
+            // Only changes which weeks an INTERVAL counts, which is checked below.
+            Some((name, _)) if name.eq_ignore_ascii_case("WKST") => {}
+            _ => parts.push(part.to_string()),
+        }
+    }
+    let rule = RecurrenceRule::parse(&parts.join(";"))?;
+
+    if matches!(rule.frequency, Frequency::Monthly | Frequency::Yearly)
+        || !rule.by_month.is_empty()
+        || !rule.by_month_day.is_empty()
+    {
+        bail!(
+            "Error: Gregorian month-based rules cannot be mapped onto Parsi dates; only DAILY and WEEKLY rules without BYMONTH or BYMONTHDAY are supported."
+        );
+    }
+    let start_weekday = weekday::weekday_number(&start)?;
+    if rule.frequency == Frequency::Weekly
+        && rule.interval > 1
+        && rule
+            .by_day
+            .iter()
+            .any(|spec| spec.weekday.number() != start_weekday)
+    {
+        bail!(
+            "Error: WEEKLY rules with INTERVAL above 1 and other weekdays than DTSTART's depend on the week start and are not supported."
+        );
+    }
+    Ok(rule)
+}
+
+/// Joins folded continuation lines (those starting with a space or tab).
+fn unfold_lines(input: &str) -> Vec<String> {
+    let mut lines: Vec<String> = Vec::new();
+    for line in input.lines() {
+        match (line.strip_prefix([' ', '\t']), lines.last_mut()) {
+            (Some(rest), Some(last)) => last.push_str(rest),
+            _ => lines.push(line.to_string()),
+        }
+    }
+    lines
+}
+
+/// Folds a content line into CRLF-terminated lines of at most 75 octets.
+fn fold_line(line: &str) -> String {
+    let mut folded = String::new();
+    let mut width = 0;
+    for c in line.chars() {
+        if width + c.len_utf8() > MAX_LINE_OCTETS {
+            folded.push_str("\r\n ");
+            width = 1;
+        }
+        folded.push(c);
+        width += c.len_utf8();
+    }
+    folded.push_str("\r\n");
+    folded
+}
+
+/// Parses a `DATE` (`20250321`) or `DATE-TIME` (`20250321T090000`, optionally with `Z`).
+fn parse_date_value(value: &str, is_date_only: bool, tz: Tz) -> Result<ParsiDate> {
+    let value = value.trim();
+    let date = if is_date_only || !value.contains('T') {
+        NaiveDate::parse_from_str(value, "%Y%m%d").ok()
+    } else if let Some(utc) = value.strip_suffix(['Z', 'z']) {
+        NaiveDateTime::parse_from_str(utc, "%Y%m%dT%H%M%S")
+            .ok()
+            .map(|datetime| datetime.and_utc().with_timezone(&tz).date_naive())
+    } else {
+        NaiveDateTime::parse_from_str(value, "%Y%m%dT%H%M%S")
+            .ok()
+            .map(|datetime| datetime.date())
+    };
+    let date = date.with_context(|| {
+        format!(
+            "Error: '{}' is not an iCalendar date (YYYYMMDD) or date-time (YYYYMMDDTHHMMSS).",
+            value
+        )
+    })?;
+    ParsiDate::from_gregorian(date).map_err(|e| map_mitra_error(e, "converting to Parsi"))
+}
+
+/// Parses a comma-separated `RDATE` or `EXDATE` list.
+fn parse_date_list(value: &str, is_date_only: bool, tz: Tz) -> Result<Vec<ParsiDate>> {
+    value
+        .split(',')
+        .map(|item| parse_date_value(item, is_date_only, tz))
+        .collect()
+}
+
+fn gregorian(date: &ParsiDate) -> Result<NaiveDate> {
+    date.to_gregorian()
+        .map_err(|e| map_mitra_error(e, "converting to Gregorian"))
+}
+
+/// Escapes a `TEXT` value (backslash, semicolon, comma, newline).
+fn escape_text(value: &str) -> String {
+    value
+        .replace('\\', "\\\\")
+        .replace(';', "\\;")
+        .replace(',', "\\,")
+        .replace('\n', "\\n")
+}
+
+/// Reverses `escape_text`.
+fn unescape_text(value: &str) -> String {
+    let mut text = String::new();
+    let mut chars = value.chars();
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            text.push(c);
+            continue;
+        }
+        match chars.next() {
+            Some('n' | 'N') => text.push('\n'),
+            Some(escaped) => text.push(escaped),
+            None => text.push('\\'),
+        }
+    }
+    text
+}
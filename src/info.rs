@@ -0,0 +1,183 @@
+//  ~/src/info.rs
+//
+//  * Copyright (C) 2024–2025 Parsicore <parsicore.dev@gmail.com>
+//  * Package : mitra
+//  * License : Apache-2.0
+//  * Version : 2.3.0
+//  * URL     : https://github.com/parsicore/Mitra
+//  * Sign: mitra-20250419-bd5fbe728fa2-5836b45f25d83501625cc5529193d5f0
+//
+//! Collects the detailed information shown by the `info` command into a single
+//! serializable structure, so it can be rendered either as text or as JSON.
+
+use crate::events::{self, Event};
+use crate::quarter::QuarterExt;
+use crate::utils::map_mitra_error;
+use crate::weekday::{WeekExt, WeekStart};
+use crate::zodiac;
+use anyhow::Result;
+use clap::ValueEnum;
+use parsidate::{ParsiDate, ParsiDateTime};
+use serde::Serialize;
+
+/// A single `DateInfo` value, selected with `info --field` (kebab-case on the command line).
+#[derive(ValueEnum, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum InfoField {
+    Date,
+    Time,
+    Weekday,
+    DayOfYear,
+    WeekOfYear,
+    DaysInMonth,
+    IsLeapYear,
+    AnimalYear,
+    ZodiacSign,
+    Season,
+    Quarter,
+    Gregorian,
+    FirstDayOfWeek,
+    LastDayOfWeek,
+    FirstDayOfQuarter,
+    LastDayOfQuarter,
+    FirstDayOfMonth,
+    LastDayOfMonth,
+    FirstDayOfYear,
+    LastDayOfYear,
+}
+
+/// All calculated facts about a single Parsi date/datetime.
+#[derive(Serialize, Debug, Clone)]
+pub struct DateInfo {
+    /// The raw input string as provided by the user.
+    pub input: String,
+    /// The parsed date in `YYYY/MM/DD` form.
+    pub date: String,
+    /// The parsed time in `HH:MM:SS` form, if the input included a time part.
+    pub time: Option<String>,
+    /// The Persian weekday name (e.g., "شنبه").
+    pub weekday: String,
+    /// The day of the year (1-366).
+    pub day_of_year: u32,
+    /// The Saturday-first week of the year; week 1 contains 1 Farvardin.
+    pub week_of_year: u32,
+    /// Number of days in the date's month.
+    pub days_in_month: u32,
+    /// Whether the date's year is a Persian leap year.
+    pub is_leap_year: bool,
+    /// The traditional animal year name (e.g., "سال مار").
+    pub animal_year: String,
+    /// The solar zodiac sign of the date's month (e.g., "برج حمل").
+    pub zodiac_sign: String,
+    /// The season of the date (e.g., "بهار").
+    pub season: String,
+    /// The quarter of the year (1-4); quarter 1 is spring.
+    pub quarter: u32,
+    /// The Gregorian equivalent (date, or datetime if the input had time).
+    pub gregorian: String,
+    /// The Saturday that starts the date's week.
+    pub first_day_of_week: String,
+    /// The Friday that ends the date's week.
+    pub last_day_of_week: String,
+    /// The first day of the date's quarter.
+    pub first_day_of_quarter: String,
+    /// The last day of the date's quarter.
+    pub last_day_of_quarter: String,
+    /// The first day of the date's month.
+    pub first_day_of_month: String,
+    /// The last day of the date's month.
+    pub last_day_of_month: String,
+    /// 1 Farvardin of the date's year.
+    pub first_day_of_year: String,
+    /// The last day of Esfand in the date's year (29 or 30).
+    pub last_day_of_year: String,
+    /// Events recorded for this date (empty if none).
+    pub events: Vec<Event>,
+}
+
+impl DateInfo {
+    /// Builds the info structure for a parsed datetime.
+    /// `was_datetime` controls whether the time part is reported.
+    pub fn new(input: &str, pdt: &ParsiDateTime, was_datetime: bool) -> Result<Self> {
+        let date = pdt.date();
+
+        let weekday = date
+            .weekday()
+            .map_err(|e| map_mitra_error(e, "getting weekday"))?;
+        let day_of_year = date
+            .ordinal()
+            .map_err(|e| map_mitra_error(e, "getting day of year"))?;
+        let gregorian_ndt = pdt
+            .to_gregorian()
+            .map_err(|e| map_mitra_error(e, "converting to Gregorian"))?;
+
+        let (time, gregorian) = if was_datetime {
+            (
+                Some(format!(
+                    "{:02}:{:02}:{:02}",
+                    pdt.hour(),
+                    pdt.minute(),
+                    pdt.second()
+                )),
+                gregorian_ndt.format("%Y-%m-%d %H:%M:%S").to_string(),
+            )
+        } else {
+            (None, gregorian_ndt.format("%Y-%m-%d").to_string())
+        };
+
+        Ok(DateInfo {
+            input: input.to_string(),
+            date: date.to_string(),
+            time,
+            weekday,
+            day_of_year,
+            week_of_year: date.week_of_year_from(WeekStart::Sat)?,
+            days_in_month: ParsiDate::days_in_month(date.year(), date.month()),
+            is_leap_year: ParsiDate::is_persian_leap_year(date.year()),
+            animal_year: zodiac::animal_year_name(date.year()),
+            zodiac_sign: zodiac::sign_name(date.month()),
+            season: date
+                .season()
+                .map_err(|e| map_mitra_error(e, "getting season"))?
+                .name_persian()
+                .to_string(),
+            quarter: date.quarter(),
+            gregorian,
+            first_day_of_week: date.start_of_week(WeekStart::Sat)?.to_string(),
+            last_day_of_week: date.end_of_week(WeekStart::Sat)?.to_string(),
+            first_day_of_quarter: date.first_day_of_quarter()?.to_string(),
+            last_day_of_quarter: date.last_day_of_quarter()?.to_string(),
+            first_day_of_month: date.first_day_of_month().to_string(),
+            last_day_of_month: date.last_day_of_month().to_string(),
+            first_day_of_year: date.first_day_of_year().to_string(),
+            last_day_of_year: date.last_day_of_year().to_string(),
+            events: events::get_events_for_date(date.year(), date.month(), date.day())
+                .unwrap_or_default(),
+        })
+    }
+
+    /// Returns one field as a bare string, or `None` for `time` when the input had no time part.
+    pub fn field(&self, field: InfoField) -> Option<String> {
+        Some(match field {
+            InfoField::Date => self.date.clone(),
+            InfoField::Time => return self.time.clone(),
+            InfoField::Weekday => self.weekday.clone(),
+            InfoField::DayOfYear => self.day_of_year.to_string(),
+            InfoField::WeekOfYear => self.week_of_year.to_string(),
+            InfoField::DaysInMonth => self.days_in_month.to_string(),
+            InfoField::IsLeapYear => self.is_leap_year.to_string(),
+            InfoField::AnimalYear => self.animal_year.clone(),
+            InfoField::ZodiacSign => self.zodiac_sign.clone(),
+            InfoField::Season => self.season.clone(),
+            InfoField::Quarter => self.quarter.to_string(),
+            InfoField::Gregorian => self.gregorian.clone(),
+            InfoField::FirstDayOfWeek => self.first_day_of_week.clone(),
+            InfoField::LastDayOfWeek => self.last_day_of_week.clone(),
+            InfoField::FirstDayOfQuarter => self.first_day_of_quarter.clone(),
+            InfoField::LastDayOfQuarter => self.last_day_of_quarter.clone(),
+            InfoField::FirstDayOfMonth => self.first_day_of_month.clone(),
+            InfoField::LastDayOfMonth => self.last_day_of_month.clone(),
+            InfoField::FirstDayOfYear => self.first_day_of_year.clone(),
+            InfoField::LastDayOfYear => self.last_day_of_year.clone(),
+        })
+    }
+}
@@ -0,0 +1,119 @@
+//  ~/src/julian.rs
+//
+//  * Copyright (C) 2024–2025 Parsicore <parsicore.dev@gmail.com>
+//  * Package : mitra
+//  * License : Apache-2.0
+//  * Version : 2.3.0
+//  * URL     : https://github.com/parsicore/Mitra
+//  * Sign: mitra-20250419-bd5fbe728fa2-5836b45f25d83501625cc5529193d5f0
+//
+//! The Julian (old-style) calendar, for documents dated before a country switched to
+//! the Gregorian calendar (1582 in Catholic Europe, 1752 in Britain, 1918 in Russia).
+//! Conversion goes through the Julian Day Number to chrono's proleptic Gregorian
+//! `NaiveDate`, and from there to Parsi dates like any other Gregorian input.
+
+use crate::utils::to_ascii_digits;
+use anyhow::{Context, Result, bail};
+use chrono::{Datelike, NaiveDate, NaiveTime};
+use std::fmt;
+
+/// Julian Day Number of 0001-01-01 (proleptic Gregorian), day 1 of chrono's CE count.
+const JDN_CE_DAY_ONE: i64 = 1_721_426;
+
+/// A date in the Julian calendar, years 1 through 9999.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct JulianDate {
+    year: i32,
+    month: u32,
+    day: u32,
+}
+
+impl JulianDate {
+    /// Creates a Julian date, validating it against Julian month lengths
+    /// (every fourth year is a leap year, without the Gregorian century exception).
+    pub fn new(year: i32, month: u32, day: u32) -> Result<JulianDate> {
+        if !(1..=9999).contains(&year) {
+            bail!("Error: Julian year {} is out of range (1-9999).", year);
+        }
+        if !(1..=12).contains(&month) || day < 1 || day > days_in_month(year, month) {
+            bail!(
+                "Error: {:04}-{:02}-{:02} is not a valid Julian date.",
+                year,
+                month,
+                day
+            );
+        }
+        Ok(JulianDate { year, month, day })
+    }
+
+    /// Converts to the equivalent (proleptic) Gregorian date.
+    pub fn to_gregorian(self) -> Result<NaiveDate> {
+        let a = (14 - self.month as i64) / 12;
+        let y = self.year as i64 + 4800 - a;
+        let m = self.month as i64 + 12 * a - 3;
+        let jdn = self.day as i64 + (153 * m + 2) / 5 + 365 * y + y / 4 - 32083;
+        NaiveDate::from_num_days_from_ce_opt((jdn - JDN_CE_DAY_ONE + 1) as i32)
+            .with_context(|| format!("Error: Julian date {} is out of range.", self))
+    }
+
+    /// Converts a (proleptic) Gregorian date to the Julian calendar.
+    pub fn from_gregorian(date: NaiveDate) -> Result<JulianDate> {
+        let jdn = date.num_days_from_ce() as i64 - 1 + JDN_CE_DAY_ONE;
+        let c = jdn + 32082;
+        let d = (4 * c + 3) / 1461;
+        let e = c - 1461 * d / 4;
+        let m = (5 * e + 2) / 153;
+        let day = (e - (153 * m + 2) / 5 + 1) as u32;
+        let month = (m + 3 - 12 * (m / 10)) as u32;
+        let year = (d - 4800 + m / 10) as i32;
+        JulianDate::new(year, month, day)
+    }
+}
+
+impl fmt::Display for JulianDate {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:04}-{:02}-{:02}", self.year, self.month, self.day)
+    }
+}
+
+fn days_in_month(year: i32, month: u32) -> u32 {
+    match month {
+        2 if year % 4 == 0 => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+/// Parses a Julian date (`YYYY-MM-DD` or `YYYY/MM/DD`), optionally followed by a time
+/// (`HH:MM:SS`, after a space or `T`). Chrono's parsers cannot be used because they
+/// reject Julian-only days such as 1700-02-29. Returns the date, the time (midnight for
+/// plain dates), and whether the input included a time.
+pub fn parse_input(input: &str) -> Result<(JulianDate, NaiveTime, bool)> {
+    let normalized = to_ascii_digits(input.trim());
+    let (date_part, time_part) = match normalized.split_once([' ', 'T']) {
+        Some((date, time)) => (date, Some(time.trim())),
+        None => (normalized.as_str(), None),
+    };
+    let invalid = || {
+        format!(
+            "Could not parse Julian date/datetime '{}'. Use formats like YYYY-MM-DD or YYYY-MM-DD HH:MM:SS",
+            input.trim()
+        )
+    };
+
+    let parts: Vec<&str> = date_part.split(['-', '/']).collect();
+    let [year, month, day] = parts[..] else {
+        bail!(invalid());
+    };
+    let date = JulianDate::new(
+        year.parse().with_context(invalid)?,
+        month.parse().with_context(invalid)?,
+        day.parse().with_context(invalid)?,
+    )?;
+    let time = match time_part {
+        Some(time) => NaiveTime::parse_from_str(time, "%H:%M:%S").with_context(invalid)?,
+        None => NaiveTime::MIN,
+    };
+    Ok((date, time, time_part.is_some()))
+}
@@ -0,0 +1,120 @@
+//  ~/src/leave.rs
+//
+//  * Copyright (C) 2024–2025 Parsicore <parsicore.dev@gmail.com>
+//  * Package : mitra
+//  * License : Apache-2.0
+//  * Version : 2.3.0
+//  * URL     : https://github.com/parsicore/Mitra
+//  * Sign: mitra-20250419-bd5fbe728fa2-5836b45f25d83501625cc5529193d5f0
+//
+//! Leave-day accounting for the `leave` command: walks a date range and counts the
+//! days that consume leave, i.e. business days, optionally also skipping Thursdays
+//! for workplaces with a five-day week.
+
+use crate::business_days::{self, DayKind};
+use crate::events;
+use crate::range::ParsiDateRangeExt;
+use crate::utils::{map_mitra_error, signed_days_between};
+use crate::weekday::Weekday;
+use anyhow::{Result, bail};
+use parsidate::ParsiDate;
+use serde::Serialize;
+
+/// Longest range accepted, in days (about ten years).
+const MAX_RANGE_DAYS: i64 = 3660;
+
+/// Why a day in the range does not consume leave.
+#[derive(Serialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum Exclusion {
+    Friday,
+    Holiday,
+    Thursday,
+}
+
+/// One day of the range.
+#[derive(Serialize, Debug)]
+pub struct LeaveDay {
+    pub date: String,
+    pub weekday: String,
+    /// Whether the day consumes leave.
+    pub counted: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub excluded: Option<Exclusion>,
+    /// Titles of the official holidays on this day.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub holidays: Vec<String>,
+}
+
+/// The leave consumed by a range, with a per-day breakdown.
+#[derive(Serialize, Debug)]
+pub struct LeaveSummary {
+    pub start: String,
+    pub end: String,
+    pub calendar_days: u32,
+    pub leave_days: u32,
+    pub days: Vec<LeaveDay>,
+}
+
+/// Computes the leave consumed by `start..=end`. Fridays and official holidays never
+/// count; Thursdays are skipped too when `exclude_thursdays` is set.
+pub fn calculate(
+    start: &ParsiDate,
+    end: &ParsiDate,
+    exclude_thursdays: bool,
+) -> Result<LeaveSummary> {
+    let span = signed_days_between(start, end)?;
+    if span < 0 {
+        bail!("Error: Leave start {} is after leave end {}.", start, end);
+    }
+    if span >= MAX_RANGE_DAYS {
+        bail!(
+            "Error: Leave ranges are limited to {} days, got {}.",
+            MAX_RANGE_DAYS,
+            span + 1
+        );
+    }
+
+    let mut days = Vec::new();
+    for current in start.iter_to(*end) {
+        let excluded = match business_days::classify(&current)? {
+            DayKind::Holiday => Some(Exclusion::Holiday),
+            DayKind::Friday => Some(Exclusion::Friday),
+            DayKind::Workday
+                if exclude_thursdays && Weekday::of(&current)? == Weekday::Thursday =>
+            {
+                Some(Exclusion::Thursday)
+            }
+            DayKind::Workday => None,
+        };
+        let mut holidays: Vec<String> = if excluded == Some(Exclusion::Holiday) {
+            events::get_events_for_date(current.year(), current.month(), current.day())
+                .unwrap_or_default()
+                .into_iter()
+                .filter(|e| e.holiday)
+                .map(|e| e.title)
+                .collect()
+        } else {
+            Vec::new()
+        };
+        // The event data lists some holidays under both calendars on the same day.
+        holidays.dedup();
+        days.push(LeaveDay {
+            date: current.to_string(),
+            weekday: current
+                .weekday()
+                .map_err(|e| map_mitra_error(e, "getting weekday"))?,
+            counted: excluded.is_none(),
+            excluded,
+            holidays,
+        });
+    }
+
+    Ok(LeaveSummary {
+        start: start.to_string(),
+        end: end.to_string(),
+        calendar_days: days.len() as u32,
+        leave_days: days.iter().filter(|d| d.counted).count() as u32,
+        days,
+    })
+}
@@ -11,10 +11,45 @@
 //! It parses command-line arguments and dispatches to the appropriate handler function.
 
 // Declare the modules within the src directory
+mod academic;
+mod anniversary;
+mod applet;
+mod astronomy;
+mod batch;
+mod business_days;
 mod cli;
+mod clock;
+mod dataset_schema;
+mod doctor;
+mod duration;
 mod events;
+mod export;
+mod feed;
+mod fiscal;
 mod handlers;
+mod hijri;
+mod ics;
+mod info;
+mod julian;
+mod leave;
+mod month_grid;
+mod natural;
+#[cfg(feature = "proleptic")]
+mod proleptic;
+mod quarter;
+mod range;
+mod recurrence;
+mod rfc3339;
+mod rpc;
+mod schedule;
+mod statusbar;
+mod timezone;
 mod utils;
+mod weekday;
+mod worldclock;
+#[cfg(feature = "lookup-tables")]
+mod year_table;
+mod zodiac;
 
 use anyhow::Result;
 use clap::Parser;
@@ -24,11 +59,42 @@ fn main() -> Result<()> {
     // Parse the command-line arguments using the definition from the cli module.
     let cli = Cli::parse();
 
+    // Configure diagnostics from the global -v/-q flags. Warnings and errors are shown by default.
+    // The MITRA_LOG environment variable (e.g., MITRA_LOG=debug) overrides the flags when set.
+    let log_level = if cli.quiet {
+        log::LevelFilter::Off
+    } else {
+        match cli.verbose {
+            0 => log::LevelFilter::Warn,
+            1 => log::LevelFilter::Info,
+            2 => log::LevelFilter::Debug,
+            _ => log::LevelFilter::Trace,
+        }
+    };
+    env_logger::Builder::new()
+        .filter_level(log_level)
+        .parse_env("MITRA_LOG")
+        .format_timestamp(None)
+        .init();
+
+    // Strict parsing applies to every date input, so it is set once for the whole run.
+    utils::set_strict_parsing(cli.strict);
+    utils::set_digit_style(if cli.persian_digits {
+        utils::DigitStyle::Persian
+    } else {
+        utils::DigitStyle::Ascii
+    });
+
+    // Global output mode shared by the handlers that support it.
+    let raw = cli.raw;
+
     // Dispatch execution based on the parsed subcommand.
     // Calls the public handler functions defined in the handlers module.
     // If no subcommand is provided, default to the 'now' command.
     match cli.command {
-        Some(Commands::Now) => handlers::handle_now(),
+        Some(Commands::Now { tz }) => handlers::handle_now(tz),
+        Some(Commands::Stamp { pattern }) => handlers::handle_stamp(pattern),
+        Some(Commands::Timestamp { value, tz }) => handlers::handle_timestamp(value, tz),
         Some(Commands::Add {
             base_datetime,
             days,
@@ -37,7 +103,17 @@ fn main() -> Result<()> {
             hours,
             minutes,
             seconds,
-        }) => handlers::handle_add(base_datetime, days, months, years, hours, minutes, seconds),
+            business_days,
+        }) => handlers::handle_add(
+            base_datetime,
+            days,
+            months,
+            years,
+            hours,
+            minutes,
+            seconds,
+            business_days,
+        ),
         Some(Commands::Sub {
             base_datetime,
             days,
@@ -46,7 +122,17 @@ fn main() -> Result<()> {
             hours,
             minutes,
             seconds,
-        }) => handlers::handle_sub(base_datetime, days, months, years, hours, minutes, seconds),
+            business_days,
+        }) => handlers::handle_sub(
+            base_datetime,
+            days,
+            months,
+            years,
+            hours,
+            minutes,
+            seconds,
+            business_days,
+        ),
         Some(Commands::Format {
             datetime_string,
             style,
@@ -55,16 +141,88 @@ fn main() -> Result<()> {
         Some(Commands::Diff {
             datetime1,
             datetime2,
-        }) => handlers::handle_diff(datetime1, datetime2),
-        Some(Commands::Weekday { date_string }) => handlers::handle_weekday(date_string),
-        Some(Commands::ToGregorian { parsi_datetime }) => {
-            handlers::handle_to_gregorian(parsi_datetime)
-        }
-        Some(Commands::FromGregorian { gregorian_datetime }) => {
-            handlers::handle_from_gregorian(gregorian_datetime)
+            business_days,
+        }) => handlers::handle_diff(datetime1, datetime2, business_days, raw),
+        Some(Commands::Until { date_string }) => handlers::handle_until(date_string),
+        Some(Commands::Since { date_string }) => handlers::handle_since(date_string),
+        Some(Commands::Weekday {
+            date_strings,
+            from,
+            to,
+        }) => handlers::handle_weekday(date_strings, from, to, raw),
+        Some(Commands::Range {
+            start,
+            end,
+            every,
+            count,
+            reverse,
+        }) => handlers::handle_range(start, end, every, count, reverse),
+        Some(Commands::Recur {
+            rule,
+            start,
+            end,
+            count,
+            ics,
+            summary,
+        }) => handlers::handle_recur(rule, start, end, count, ics, summary),
+        Some(Commands::Ics { file, from, to, tz }) => handlers::handle_ics(file, from, to, tz),
+        Some(Commands::ToGregorian {
+            parsi_datetime,
+            tz,
+            to_tz,
+            rfc3339,
+        }) => handlers::handle_to_gregorian(parsi_datetime, tz, to_tz, rfc3339),
+        Some(Commands::FromGregorian {
+            gregorian_datetime,
+            tz,
+            to_tz,
+        }) => handlers::handle_from_gregorian(gregorian_datetime, tz, to_tz),
+        Some(Commands::ToJulian { parsi_datetime }) => handlers::handle_to_julian(parsi_datetime),
+        Some(Commands::FromJulian { julian_datetime }) => {
+            handlers::handle_from_julian(julian_datetime)
         }
-        Some(Commands::IsLeap { year }) => handlers::handle_is_leap(year),
-        Some(Commands::Info { datetime_string }) => handlers::handle_info(datetime_string),
+        Some(Commands::ToHijri {
+            datetime_string,
+            gregorian,
+        }) => handlers::handle_to_hijri(datetime_string, gregorian),
+        Some(Commands::FromHijri {
+            hijri_datetime,
+            gregorian,
+        }) => handlers::handle_from_hijri(hijri_datetime, gregorian),
+        Some(Commands::IsLeap { year }) => handlers::handle_is_leap(year, raw),
+        Some(Commands::IsWorkday {
+            date_string,
+            fail_if_holiday,
+        }) => handlers::handle_is_workday(date_string, fail_if_holiday, raw),
+        Some(Commands::Academic {
+            date_string,
+            term_start,
+            second_term,
+            classes_end,
+            json,
+        }) => handlers::handle_academic(date_string, &term_start, &second_term, &classes_end, json),
+        Some(Commands::Anniversary {
+            date_string,
+            n,
+            gregorian,
+        }) => handlers::handle_anniversary(date_string, n, gregorian),
+        Some(Commands::Leave {
+            start,
+            end,
+            exclude_thursdays,
+            json,
+        }) => handlers::handle_leave(start, end, exclude_thursdays, json),
+        Some(Commands::Worldclock {
+            datetime_string,
+            cities,
+            json,
+        }) => handlers::handle_worldclock(datetime_string, cities, json),
+        Some(Commands::Schedule { at, exec, next }) => handlers::handle_schedule(at, exec, next),
+        Some(Commands::Info {
+            datetime_string,
+            json,
+            field,
+        }) => handlers::handle_info(datetime_string, json, field, raw),
         Some(Commands::Parse {
             input_string,
             pattern,
@@ -74,8 +232,52 @@ fn main() -> Result<()> {
             year,
             three,
             show_year,
-        }) => handlers::handle_cal(month, year, three, show_year),
-        Some(Commands::Events { date_string }) => handlers::handle_events(date_string),
-        None => handlers::handle_now(),
+            first_day,
+            events,
+        }) => handlers::handle_cal(month, year, three, show_year, first_day, events),
+        Some(Commands::AppletFeed { pretty }) => handlers::handle_applet_feed(pretty),
+        Some(Commands::Statusbar {
+            format,
+            json,
+            interval,
+        }) => handlers::handle_statusbar(&format, json, interval),
+        Some(Commands::Rpc) => handlers::handle_rpc(),
+        Some(Commands::Pick {
+            month,
+            year,
+            events,
+            select,
+        }) => handlers::handle_pick(month, year, events, select, raw),
+        Some(Commands::Batch {
+            operation,
+            skip_errors,
+        }) => handlers::handle_batch(operation, skip_errors),
+        Some(Commands::Doctor) => handlers::handle_doctor(),
+        Some(Commands::Zodiac { year }) => handlers::handle_zodiac(year),
+        Some(Commands::Sign { date_string }) => handlers::handle_sign(date_string),
+        Some(Commands::Map { year }) => handlers::handle_map(year),
+        Some(Commands::Export {
+            year,
+            year_range,
+            format,
+        }) => handlers::handle_export(year, year_range, format),
+        Some(Commands::Feed { year, days, format }) => handlers::handle_feed(year, days, format),
+        Some(Commands::Today {
+            occasions,
+            category,
+        }) => handlers::handle_today(occasions, category),
+        Some(Commands::OnThisDay {
+            date_string,
+            category,
+        }) => handlers::handle_on_this_day(date_string, category),
+        Some(Commands::Events { date_strings }) => handlers::handle_events(date_strings),
+        Some(Commands::FiscalDeadlines {
+            year,
+            upcoming,
+            rules,
+            json,
+        }) => handlers::handle_fiscal_deadlines(year, upcoming, rules, json),
+        Some(Commands::Nowruz { year }) => handlers::handle_nowruz(year),
+        None => handlers::handle_now(None),
     }
 }
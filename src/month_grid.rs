@@ -0,0 +1,97 @@
+//  ~/src/month_grid.rs
+//
+//  * Copyright (C) 2024–2025 Parsicore <parsicore.dev@gmail.com>
+//  * Package : mitra
+//  * License : Apache-2.0
+//  * Version : 2.3.0
+//  * URL     : https://github.com/parsicore/Mitra
+//  * Sign: mitra-20250419-bd5fbe728fa2-5836b45f25d83501625cc5529193d5f0
+//
+//! A frontend-neutral model of one month laid out as weeks of day cells.
+//! The `cal` text renderer and the applet JSON feed both consume it, so the
+//! weekday alignment and today/holiday/event flags are computed in one place.
+
+use crate::events;
+use crate::utils::map_mitra_error;
+use crate::weekday::{WeekStart, weekday_number};
+use anyhow::Result;
+use parsidate::ParsiDate;
+use serde::Serialize;
+
+/// One month as rows of seven cells. Cells before the first day and after the
+/// last day of the month are `None` (`null` in JSON).
+#[derive(Serialize, Debug, Clone)]
+pub struct MonthGrid {
+    pub year: i32,
+    pub month: u32,
+    pub month_name: String,
+    pub weeks: Vec<[Option<DayCell>; 7]>,
+}
+
+/// A single day within the month grid.
+#[derive(Serialize, Debug, Clone, Copy)]
+pub struct DayCell {
+    pub day: u32,
+    pub is_today: bool,
+    pub is_holiday: bool,
+    pub has_events: bool,
+}
+
+impl DayCell {
+    /// The calendar indicator for this day: '*' for holidays, '+' for other events, ' ' otherwise.
+    pub fn indicator(&self) -> char {
+        if self.is_holiday {
+            '*'
+        } else if self.has_events {
+            '+'
+        } else {
+            ' '
+        }
+    }
+}
+
+impl MonthGrid {
+    /// Lays out the given month with `week_start` in the first column,
+    /// flagging `today` if it falls within the month.
+    pub fn new(year: i32, month: u32, today: &ParsiDate, week_start: WeekStart) -> Result<Self> {
+        let first_day_of_month = ParsiDate::new(year, month, 1)
+            .map_err(|e| map_mitra_error(e, &format!("creating date {}-{}-1", year, month)))?;
+
+        // Get first weekday (0=Sat, 6=Fri)
+        let first_weekday_sat = weekday_number(&first_day_of_month)?;
+        // Column of the first day once the week is rotated to start on `week_start`.
+        let first_weekday = (first_weekday_sat + 7 - week_start.offset()) % 7;
+
+        let is_current_month = year == today.year() && month == today.month();
+        let days_in_month = ParsiDate::days_in_month(year, month);
+
+        let mut weeks: Vec<[Option<DayCell>; 7]> = Vec::with_capacity(6);
+        let mut week: [Option<DayCell>; 7] = [None; 7];
+        let mut date = first_day_of_month;
+        for day in 1..=days_in_month {
+            let column = ((first_weekday + day - 1) % 7) as usize;
+            week[column] = Some(DayCell {
+                day,
+                is_today: is_current_month && day == today.day(),
+                is_holiday: events::is_holiday(&date),
+                has_events: events::get_event_indicator(year, month, day).is_some(),
+            });
+            if column == 6 || day == days_in_month {
+                weeks.push(week);
+                week = [None; 7];
+            }
+            if day < days_in_month {
+                date = date
+                    .add_days(1)
+                    .map_err(|e| map_mitra_error(e, "iterating over month"))?;
+            }
+        }
+
+        Ok(MonthGrid {
+            year,
+            month,
+            month_name: first_day_of_month.format("%B"),
+            weeks,
+        })
+    }
+}
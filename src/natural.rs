@@ -0,0 +1,304 @@
+//  ~/src/natural.rs
+//
+//  * Copyright (C) 2024–2025 Parsicore <parsicore.dev@gmail.com>
+//  * Package : mitra
+//  * License : Apache-2.0
+//  * Version : 2.3.0
+//  * URL     : https://github.com/parsicore/Mitra
+//  * Sign: mitra-20250419-bd5fbe728fa2-5836b45f25d83501625cc5529193d5f0
+//
+//! A small natural-language parser for relative dates: everyday Persian phrases such as
+//! "فردا", "سه‌شنبهٔ آینده", "جمعه گذشته", "هفته بعد", "۳ روز دیگر", or "اول ماه آینده",
+//! their English counterparts ("tomorrow", "next saturday", "last week"), and compact
+//! offsets ("+3d", "-2w", "+1m", "-1y").
+//!
+//! Phrases are resolved relative to a given "today" date. Input is normalized first
+//! (Persian/Arabic digits, Arabic letter variants, ZWNJ, diacritics, spaces, and ASCII
+//! case are unified), so "سه شنبه ی آینده" and "سه‌شنبهٔ آینده" are treated the same way.
+
+use crate::weekday::weekday_number;
+use parsidate::ParsiDate;
+
+/// Persian weekday names with spaces removed, indexed by Saturday-based weekday number (0=Sat).
+const WEEKDAYS: [&str; 7] = [
+    "شنبه",
+    "یکشنبه",
+    "دوشنبه",
+    "سهشنبه",
+    "چهارشنبه",
+    "پنجشنبه",
+    "جمعه",
+];
+
+/// English weekday names and their three-letter abbreviations, indexed like `WEEKDAYS`.
+const ENGLISH_WEEKDAYS: [(&str, &str); 7] = [
+    ("saturday", "sat"),
+    ("sunday", "sun"),
+    ("monday", "mon"),
+    ("tuesday", "tue"),
+    ("wednesday", "wed"),
+    ("thursday", "thu"),
+    ("friday", "fri"),
+];
+
+/// Single words for days near today, with their offsets in days.
+const DAY_WORDS: [(&str, i64); 10] = [
+    ("امروز", 0),
+    ("فردا", 1),
+    ("پسفردا", 2),
+    ("دیروز", -1),
+    ("پریروز", -2),
+    ("today", 0),
+    ("tomorrow", 1),
+    ("dayaftertomorrow", 2),
+    ("yesterday", -1),
+    ("daybeforeyesterday", -2),
+];
+
+/// Words meaning "next/coming" and "last/previous" that can follow a weekday or unit.
+const FORWARD_WORDS: [&str; 4] = ["آینده", "بعدی", "بعد", "دیگر"];
+const BACKWARD_WORDS: [&str; 4] = ["گذشته", "قبلی", "قبل", "پیش"];
+
+/// The direction a phrase points in, relative to today.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Direction {
+    Forward,
+    Backward,
+    Current,
+}
+
+/// Attempts to interpret `input` as a relative date (see the module documentation) from `today`.
+/// Returns `None` if the phrase is not recognized or the result falls outside the supported range.
+pub fn parse_relative(input: &str, today: &ParsiDate) -> Option<ParsiDate> {
+    let compact = normalize(input);
+    if compact.is_empty() {
+        return None;
+    }
+
+    parse_day_word(&compact, today)
+        .or_else(|| parse_compact_offset(&compact, today))
+        .or_else(|| parse_english_phrase(&compact, today))
+        .or_else(|| parse_weekday_phrase(&compact, today))
+        .or_else(|| parse_boundary_phrase(&compact, today))
+        .or_else(|| parse_offset_phrase(&compact, today))
+}
+
+/// Normalizes a phrase into a compact form: ASCII digits, standard Persian letters,
+/// lowercase ASCII letters, and no spaces, ZWNJ, or diacritics.
+fn normalize(input: &str) -> String {
+    input
+        .chars()
+        .filter_map(|c| match c {
+            '۰'..='۹' => char::from_u32(c as u32 - '۰' as u32 + '0' as u32),
+            '٠'..='٩' => char::from_u32(c as u32 - '٠' as u32 + '0' as u32),
+            'ي' | 'ى' => Some('ی'),
+            'ك' => Some('ک'),
+            'ة' | 'ۀ' => Some('ه'),
+            // ZWNJ, whitespace, and combining marks (including the ezafe hamza "ٔ") are dropped.
+            '\u{200C}' | '\u{200D}' | '\u{064B}'..='\u{065F}' | '\u{0670}' => None,
+            c if c.is_whitespace() => None,
+            c => Some(c.to_ascii_lowercase()),
+        })
+        .collect()
+}
+
+/// Strips a trailing direction word from `rest`, also accepting an ezafe "ی" before it
+/// (e.g., "یآینده"). An empty remainder means no direction was given.
+fn parse_direction(rest: &str) -> Option<Direction> {
+    let rest = match rest.strip_prefix('ی') {
+        // Only treat "ی" as an ezafe if a direction word follows it.
+        Some(after) if !after.is_empty() => after,
+        _ => rest,
+    };
+    if rest.is_empty() || rest == "این" {
+        return Some(Direction::Current);
+    }
+    if FORWARD_WORDS.contains(&rest) {
+        return Some(Direction::Forward);
+    }
+    if BACKWARD_WORDS.contains(&rest) {
+        return Some(Direction::Backward);
+    }
+    None
+}
+
+/// Handles "<weekday> [آینده|گذشته]" and "این <weekday>".
+/// A bare weekday means its nearest occurrence from today onwards.
+fn parse_weekday_phrase(compact: &str, today: &ParsiDate) -> Option<ParsiDate> {
+    let (this_week, phrase) = match compact.strip_prefix("این") {
+        Some(rest) => (true, rest),
+        None => (false, compact),
+    };
+
+    let (target, rest) = WEEKDAYS
+        .iter()
+        .enumerate()
+        .find_map(|(i, name)| phrase.strip_prefix(name).map(|rest| (i as u32, rest)))?;
+
+    let direction = parse_direction(rest)?;
+    if this_week && direction != Direction::Current {
+        return None;
+    }
+    shift_to_weekday(today, target, this_week, direction)
+}
+
+/// Moves from `today` to weekday `target` (Saturday-based): within the same week if
+/// `this_week`, otherwise to the next (or, going backward, previous) occurrence. With
+/// `Direction::Current`, today counts as the next occurrence.
+fn shift_to_weekday(
+    today: &ParsiDate,
+    target: u32,
+    this_week: bool,
+    direction: Direction,
+) -> Option<ParsiDate> {
+    let current = weekday_number(today).ok()?;
+    let offset: i64 = if this_week {
+        target as i64 - current as i64
+    } else {
+        match direction {
+            Direction::Forward => ((target + 7 - current - 1) % 7 + 1) as i64,
+            Direction::Backward => -(((current + 7 - target - 1) % 7 + 1) as i64),
+            Direction::Current => ((target + 7 - current) % 7) as i64,
+        }
+    };
+    today.add_days(offset).ok()
+}
+
+/// Handles single words for nearby days: "امروز", "فردا", "پس‌فردا", "دیروز", "پریروز",
+/// and "today", "tomorrow", "yesterday" (plus "day after tomorrow"/"day before yesterday").
+fn parse_day_word(compact: &str, today: &ParsiDate) -> Option<ParsiDate> {
+    let (_, offset) = DAY_WORDS.iter().find(|(word, _)| *word == compact)?;
+    today.add_days(*offset).ok()
+}
+
+/// Handles "+3d", "-2w", "+1m", "-1y": a signed count of days, weeks, months, or years.
+fn parse_compact_offset(compact: &str, today: &ParsiDate) -> Option<ParsiDate> {
+    let (sign, rest) = match compact.chars().next()? {
+        '+' => (1, &compact[1..]),
+        '-' => (-1, &compact[1..]),
+        _ => return None,
+    };
+    let unit = rest.chars().last()?;
+    let number = &rest[..rest.len() - unit.len_utf8()];
+    if number.is_empty() || !number.chars().all(|c| c.is_ascii_digit()) {
+        return None;
+    }
+    let amount = sign * number.parse::<i64>().ok()?;
+    match unit {
+        'd' => today.add_days(amount).ok(),
+        'w' => today.add_days(amount.checked_mul(7)?).ok(),
+        'm' => today.add_months(i32::try_from(amount).ok()?).ok(),
+        'y' => today.add_years(i32::try_from(amount).ok()?).ok(),
+        _ => None,
+    }
+}
+
+/// Handles "[next|last|this] <weekday>" and "next|last week|month|year" in English.
+/// As with the Persian phrases, a bare weekday means its next occurrence, today included.
+fn parse_english_phrase(compact: &str, today: &ParsiDate) -> Option<ParsiDate> {
+    let (direction, this_week, rest) = if let Some(rest) = compact.strip_prefix("next") {
+        (Direction::Forward, false, rest)
+    } else if let Some(rest) = compact.strip_prefix("last") {
+        (Direction::Backward, false, rest)
+    } else if let Some(rest) = compact.strip_prefix("this") {
+        (Direction::Current, true, rest)
+    } else {
+        (Direction::Current, false, compact)
+    };
+
+    if let Some(target) = ENGLISH_WEEKDAYS
+        .iter()
+        .position(|(name, short)| rest == *name || rest == *short)
+    {
+        return shift_to_weekday(today, target as u32, this_week, direction);
+    }
+
+    let step = match direction {
+        Direction::Forward => 1,
+        Direction::Backward => -1,
+        Direction::Current => return None,
+    };
+    match rest {
+        "week" => today.add_days(7 * step).ok(),
+        "month" => today.add_months(step as i32).ok(),
+        "year" => today.add_years(step as i32).ok(),
+        _ => None,
+    }
+}
+
+/// Handles "اول|آخر ماه|سال [این|آینده|گذشته]", e.g. "اول ماه آینده" or "آخر سال".
+fn parse_boundary_phrase(compact: &str, today: &ParsiDate) -> Option<ParsiDate> {
+    let (is_start, rest) = if let Some(rest) = compact.strip_prefix("اول") {
+        (true, rest)
+    } else if let Some(rest) = compact.strip_prefix("آخر") {
+        (false, rest)
+    } else {
+        return None;
+    };
+    // Allow an ezafe "ِ"/"ی" and an explicit "این" before the unit ("اولِ این ماه").
+    let rest = rest.strip_prefix('ی').unwrap_or(rest);
+    let rest = rest.strip_prefix("این").unwrap_or(rest);
+
+    let step = |direction: Direction| match direction {
+        Direction::Forward => 1,
+        Direction::Backward => -1,
+        Direction::Current => 0,
+    };
+
+    if let Some(rest) = rest.strip_prefix("ماه") {
+        let month = today
+            .first_day_of_month()
+            .add_months(step(parse_direction(rest)?))
+            .ok()?;
+        Some(if is_start {
+            month
+        } else {
+            month.last_day_of_month()
+        })
+    } else if let Some(rest) = rest.strip_prefix("سال") {
+        let year = today
+            .first_day_of_year()
+            .add_years(step(parse_direction(rest)?))
+            .ok()?;
+        Some(if is_start {
+            year
+        } else {
+            year.last_day_of_year()
+        })
+    } else {
+        None
+    }
+}
+
+/// Handles "[N] روز|هفته|ماه|سال بعد|دیگر|قبل|پیش", e.g. "هفته بعد" or "3 روز دیگر".
+fn parse_offset_phrase(compact: &str, today: &ParsiDate) -> Option<ParsiDate> {
+    let digits_end = compact
+        .find(|c: char| !c.is_ascii_digit())
+        .unwrap_or(compact.len());
+    let amount: i64 = if digits_end == 0 {
+        1
+    } else {
+        compact[..digits_end].parse().ok()?
+    };
+    let rest = &compact[digits_end..];
+
+    let units = ["روز", "هفته", "ماه", "سال"];
+    let (unit, rest) = units
+        .iter()
+        .find_map(|u| rest.strip_prefix(u).map(|r| (*u, r)))?;
+
+    let signed = match parse_direction(rest)? {
+        Direction::Forward => amount,
+        Direction::Backward => -amount,
+        // "ماه" or "3 روز" on their own are not dates.
+        Direction::Current => return None,
+    };
+
+    match unit {
+        "روز" => today.add_days(signed).ok(),
+        "هفته" => today.add_days(signed.checked_mul(7)?).ok(),
+        "ماه" => today.add_months(i32::try_from(signed).ok()?).ok(),
+        "سال" => today.add_years(i32::try_from(signed).ok()?).ok(),
+        _ => None,
+    }
+}
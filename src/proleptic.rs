@@ -0,0 +1,144 @@
+//  ~/src/proleptic.rs
+//
+//  * Copyright (C) 2024–2025 Parsicore <parsicore.dev@gmail.com>
+//  * Package : mitra
+//  * License : Apache-2.0
+//  * Version : 2.3.0
+//  * URL     : https://github.com/parsicore/Mitra
+//  * Sign: mitra-20250419-bd5fbe728fa2-5836b45f25d83501625cc5529193d5f0
+//
+//! Proleptic Jalali dates before Parsi year 1 (Gregorian 622-03-21), built with
+//! the `proleptic` feature.
+//!
+//! `parsidate` only represents years 1..=9999. Earlier dates are handled here by
+//! running its 33-year leap cycle backwards: a date is shifted forward by whole
+//! cycles (each exactly 12053 days) into the supported range, converted there,
+//! and shifted back. Semantics:
+//!
+//! - Years use astronomical numbering: year 0 is the year before year 1, year -1
+//!   the year before that. Leap years keep the cycle positions of positive years
+//!   (`year.rem_euclid(33)` in 1, 5, 9, 13, 17, 22, 26, 30).
+//! - The Gregorian side is the proleptic Gregorian calendar (as in `chrono`), with
+//!   astronomical years too; no Julian calendar dates are produced.
+//! - Dates from year 1 onwards convert exactly as `parsidate` does.
+
+use crate::utils::{map_mitra_error, supported_range};
+use anyhow::{Context, Result, anyhow, bail};
+use chrono::{Duration, NaiveDate};
+use parsidate::ParsiDate;
+use std::fmt;
+
+/// Length of the leap cycle in years.
+const CYCLE_YEARS: i32 = 33;
+/// Length of the leap cycle in days: 33 common years plus 8 leap days.
+const CYCLE_DAYS: i64 = 33 * 365 + 8;
+
+/// A Parsi date that may lie before year 1.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ProlepticDate {
+    year: i32,
+    month: u32,
+    day: u32,
+}
+
+impl ProlepticDate {
+    /// Creates a date, validating month and day against the extended leap cycle.
+    pub fn new(year: i32, month: u32, day: u32) -> Result<Self> {
+        let (shifted_year, _) = shift_into_range(year)?;
+        ParsiDate::new(shifted_year, month, day).map_err(|_| {
+            anyhow!(
+                "Error: {} is not a valid proleptic Parsi date.",
+                ProlepticDate { year, month, day }
+            )
+        })?;
+        Ok(ProlepticDate { year, month, day })
+    }
+
+    /// Converts to a proleptic Gregorian date.
+    pub fn to_gregorian(self) -> Result<NaiveDate> {
+        let (shifted_year, cycles) = shift_into_range(self.year)?;
+        let shifted = ParsiDate::new(shifted_year, self.month, self.day)
+            .map_err(|e| map_mitra_error(e, "creating shifted date"))?
+            .to_gregorian()
+            .map_err(|e| map_mitra_error(e, "converting to Gregorian"))?;
+        shifted
+            .checked_sub_signed(Duration::days(CYCLE_DAYS * i64::from(cycles)))
+            .with_context(|| format!("Error: {} is too early to convert to Gregorian.", self))
+    }
+
+    /// Converts from a proleptic Gregorian date.
+    pub fn from_gregorian(date: NaiveDate) -> Result<Self> {
+        let epoch = epoch();
+        let cycles = if date < epoch {
+            let days_before = (epoch - date).num_days();
+            i32::try_from((days_before + CYCLE_DAYS - 1) / CYCLE_DAYS)
+                .context("Error: Gregorian date is too early to convert.")?
+        } else {
+            0
+        };
+        let shifted = date + Duration::days(CYCLE_DAYS * i64::from(cycles));
+        let parsi = ParsiDate::from_gregorian(shifted)
+            .map_err(|e| map_mitra_error(e, "converting from Gregorian"))?;
+        Ok(ProlepticDate {
+            year: parsi.year() - cycles * CYCLE_YEARS,
+            month: parsi.month(),
+            day: parsi.day(),
+        })
+    }
+}
+
+impl fmt::Display for ProlepticDate {
+    /// Formats as `YYYY/MM/DD`, with a leading `-` for negative years.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let sign = if self.year < 0 { "-" } else { "" };
+        write!(
+            f,
+            "{}{:04}/{:02}/{:02}",
+            sign,
+            self.year.unsigned_abs(),
+            self.month,
+            self.day
+        )
+    }
+}
+
+/// The Gregorian date of Parsi 0001/01/01.
+pub fn epoch() -> NaiveDate {
+    NaiveDate::from_ymd_opt(622, 3, 21).expect("epoch date is valid")
+}
+
+/// Splits `[-]YYYY/MM/DD` (or with `-` separators) into its numbers without
+/// validating the date. Returns `None` for anything else.
+pub fn parse_components(input: &str) -> Option<(i32, u32, u32)> {
+    let trimmed = input.trim();
+    let (negative, rest) = match trimmed.strip_prefix('-') {
+        Some(rest) => (true, rest),
+        None => (false, trimmed),
+    };
+    let mut parts = rest.split(['/', '-']);
+    let year: i32 = parts.next()?.parse().ok()?;
+    let month: u32 = parts.next()?.parse().ok()?;
+    let day: u32 = parts.next()?.parse().ok()?;
+    if parts.next().is_some() {
+        return None;
+    }
+    Some((if negative { -year } else { year }, month, day))
+}
+
+/// Returns the year moved into `parsidate`'s range by whole cycles, and the
+/// number of cycles moved. Years from 1 onwards are returned unchanged.
+fn shift_into_range(year: i32) -> Result<(i32, i32)> {
+    let last_year = *supported_range().end();
+    if year > last_year {
+        bail!(
+            "Error: Year {} is after the last supported year {}.",
+            year,
+            last_year
+        );
+    }
+    if year >= 1 {
+        return Ok((year, 0));
+    }
+    let cycles = (1 - year + CYCLE_YEARS - 1) / CYCLE_YEARS;
+    Ok((year + cycles * CYCLE_YEARS, cycles))
+}
@@ -0,0 +1,44 @@
+//  ~/src/quarter.rs
+//
+//  * Copyright (C) 2024–2025 Parsicore <parsicore.dev@gmail.com>
+//  * Package : mitra
+//  * License : Apache-2.0
+//  * Version : 2.3.0
+//  * URL     : https://github.com/parsicore/Mitra
+//  * Sign: mitra-20250419-bd5fbe728fa2-5836b45f25d83501625cc5529193d5f0
+//
+//! Quarters of the Parsi year, for reporting aligned to the Iranian fiscal year.
+//! A quarter is one of `parsidate`'s seasons (Q1 is بهار, Farvardin-Khordad), so
+//! `QuarterExt` numbers them and exposes their bounds under quarter names.
+
+use crate::utils::map_mitra_error;
+use anyhow::Result;
+use parsidate::ParsiDate;
+
+/// Quarter arithmetic on `ParsiDate`.
+pub trait QuarterExt {
+    /// The quarter of the year (1-4): Farvardin-Khordad is 1, Dey-Esfand is 4.
+    fn quarter(&self) -> u32;
+
+    /// The first day of the date's quarter.
+    fn first_day_of_quarter(&self) -> Result<ParsiDate>;
+
+    /// The last day of the date's quarter (29 or 30 Esfand in the fourth quarter).
+    fn last_day_of_quarter(&self) -> Result<ParsiDate>;
+}
+
+impl QuarterExt for ParsiDate {
+    fn quarter(&self) -> u32 {
+        (self.month() - 1) / 3 + 1
+    }
+
+    fn first_day_of_quarter(&self) -> Result<ParsiDate> {
+        self.start_of_season()
+            .map_err(|e| map_mitra_error(e, "finding start of quarter"))
+    }
+
+    fn last_day_of_quarter(&self) -> Result<ParsiDate> {
+        self.end_of_season()
+            .map_err(|e| map_mitra_error(e, "finding end of quarter"))
+    }
+}
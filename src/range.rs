@@ -0,0 +1,195 @@
+//  ~/src/range.rs
+//
+//  * Copyright (C) 2024–2025 Parsicore <parsicore.dev@gmail.com>
+//  * Package : mitra
+//  * License : Apache-2.0
+//  * Version : 2.3.0
+//  * URL     : https://github.com/parsicore/Mitra
+//  * Sign: mitra-20250419-bd5fbe728fa2-5836b45f25d83501625cc5529193d5f0
+//
+//! Iteration over Parsi dates: `ParsiDateRange` walks an inclusive range by days,
+//! weeks, or months, from either end. `ParsiDate` is a `parsidate` type, so the
+//! `iter_to`/`iter_days` entry points come from the `ParsiDateRangeExt` trait.
+//!
+//! Month steps are measured from the start date, so a range starting on the 31st
+//! yields the 31st where the month has one and the month's last day otherwise,
+//! without drifting to the 30th for the rest of the year.
+
+use anyhow::{Result, bail};
+use parsidate::{MAX_PARSI_DATE, ParsiDate};
+
+/// The distance between consecutive dates of a range.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Step {
+    Days(u32),
+    Weeks(u32),
+    Months(u32),
+}
+
+impl Step {
+    /// Parses a step such as `1d`, `2w`, or `3m` (days, weeks, months). A bare number is days.
+    pub fn parse(input: &str) -> Result<Step> {
+        let input = input.trim().to_lowercase();
+        let (number, unit) = match input.find(|c: char| !c.is_ascii_digit()) {
+            Some(index) => input.split_at(index),
+            None => (input.as_str(), "d"),
+        };
+        let count = match number.parse::<u32>() {
+            Ok(count) if count > 0 => count,
+            _ => bail!(
+                "Error: Invalid step '{}'. Use a positive number followed by d, w, or m (e.g., 1d, 2w, 3m).",
+                input
+            ),
+        };
+        Ok(match unit {
+            "d" => Step::Days(count),
+            "w" => Step::Weeks(count),
+            "m" => Step::Months(count),
+            _ => bail!(
+                "Error: Invalid step unit in '{}'. Use d (days), w (weeks), or m (months).",
+                input
+            ),
+        })
+    }
+}
+
+/// The dates from `start` through `end` (inclusive), `step` apart.
+#[derive(Debug, Clone)]
+pub struct ParsiDateRange {
+    start: ParsiDate,
+    end: ParsiDate,
+    step: Step,
+    /// Index of the next date from the front.
+    front: u64,
+    /// One past the index of the next date from the back.
+    back: u64,
+}
+
+impl ParsiDateRange {
+    /// Every day from `start` through `end`. Empty if `end` is before `start`.
+    pub fn new(start: ParsiDate, end: ParsiDate) -> ParsiDateRange {
+        ParsiDateRange {
+            start,
+            end,
+            step: Step::Days(1),
+            front: 0,
+            back: 0,
+        }
+        .with_step(Step::Days(1))
+    }
+
+    /// Every `days`-th day. Panics if `days` is zero.
+    pub fn step_by_days(self, days: u32) -> ParsiDateRange {
+        assert!(days > 0, "range step must be positive");
+        self.with_step(Step::Days(days))
+    }
+
+    /// Every `weeks`-th week (the same weekday as the start). Panics if `weeks` is zero.
+    pub fn step_by_weeks(self, weeks: u32) -> ParsiDateRange {
+        assert!(weeks > 0, "range step must be positive");
+        self.with_step(Step::Weeks(weeks))
+    }
+
+    /// The start day of every `months`-th month. Panics if `months` is zero.
+    pub fn step_by_months(self, months: u32) -> ParsiDateRange {
+        assert!(months > 0, "range step must be positive");
+        self.with_step(Step::Months(months))
+    }
+
+    /// Restarts the range with a new step.
+    fn with_step(self, step: Step) -> ParsiDateRange {
+        let mut range = ParsiDateRange {
+            step,
+            front: 0,
+            back: 0,
+            ..self
+        };
+        range.back = range.count_dates();
+        range
+    }
+
+    /// The `index`-th date of the range, ignoring the end.
+    fn date_at(&self, index: u64) -> Option<ParsiDate> {
+        let index = i64::try_from(index).ok()?;
+        match self.step {
+            Step::Days(days) => self.start.add_days(index.checked_mul(i64::from(days))?),
+            Step::Weeks(weeks) => self
+                .start
+                .add_days(index.checked_mul(7 * i64::from(weeks))?),
+            Step::Months(months) => self
+                .start
+                .add_months(i32::try_from(index.checked_mul(i64::from(months))?).ok()?),
+        }
+        .ok()
+    }
+
+    /// How many dates the range holds in total.
+    fn count_dates(&self) -> u64 {
+        if self.end < self.start {
+            return 0;
+        }
+        let days = self.start.days_between(&self.end).unwrap_or(0);
+        let months = (self.end.year() - self.start.year()) as i64 * 12 + self.end.month() as i64
+            - self.start.month() as i64;
+        let estimate = match self.step {
+            Step::Days(step) => days / i64::from(step),
+            Step::Weeks(step) => days / (7 * i64::from(step)),
+            Step::Months(step) => months / i64::from(step),
+        } as u64;
+        // Month lengths can make the estimate one step too far.
+        let mut last = estimate;
+        while last > 0 && self.date_at(last).is_none_or(|date| date > self.end) {
+            last -= 1;
+        }
+        last + 1
+    }
+}
+
+impl Iterator for ParsiDateRange {
+    type Item = ParsiDate;
+
+    fn next(&mut self) -> Option<ParsiDate> {
+        if self.front >= self.back {
+            return None;
+        }
+        let date = self.date_at(self.front);
+        self.front += 1;
+        date
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = usize::try_from(self.back - self.front).unwrap_or(usize::MAX);
+        (remaining, Some(remaining))
+    }
+}
+
+impl DoubleEndedIterator for ParsiDateRange {
+    fn next_back(&mut self) -> Option<ParsiDate> {
+        if self.front >= self.back {
+            return None;
+        }
+        self.back -= 1;
+        self.date_at(self.back)
+    }
+}
+
+impl ExactSizeIterator for ParsiDateRange {}
+
+/// Range constructors on `ParsiDate`.
+pub trait ParsiDateRangeExt {
+    /// Every day from this date through `end`, inclusive.
+    fn iter_to(self, end: ParsiDate) -> ParsiDateRange;
+
+    /// Every day from this date through the last supported date (`MAX_PARSI_DATE`).
+    fn iter_days(self) -> ParsiDateRange;
+}
+
+impl ParsiDateRangeExt for ParsiDate {
+    fn iter_to(self, end: ParsiDate) -> ParsiDateRange {
+        ParsiDateRange::new(self, end)
+    }
+
+    fn iter_days(self) -> ParsiDateRange {
+        ParsiDateRange::new(self, MAX_PARSI_DATE)
+    }
+}
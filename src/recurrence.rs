@@ -0,0 +1,577 @@
+//  ~/src/recurrence.rs
+//
+//  * Copyright (C) 2024–2025 Parsicore <parsicore.dev@gmail.com>
+//  * Package : mitra
+//  * License : Apache-2.0
+//  * Version : 2.3.0
+//  * URL     : https://github.com/parsicore/Mitra
+//  * Sign: mitra-20250419-bd5fbe728fa2-5836b45f25d83501625cc5529193d5f0
+//
+//! Recurrence rules on the Parsi calendar, written in the iCalendar RRULE syntax
+//! (RFC 5545) but evaluated on Parsi years, months, and Saturday-first weeks:
+//!
+//! - `FREQ=YEARLY;BYMONTH=1;BYMONTHDAY=1`: every year on 1 Farvardin
+//! - `FREQ=WEEKLY;INTERVAL=2;BYDAY=WE`: every second week on Wednesday
+//! - `FREQ=MONTHLY;BYDAY=-1FR`: the last Friday of each month
+//!
+//! Supported parts are `FREQ` (`DAILY`, `WEEKLY`, `MONTHLY`, `YEARLY`), `INTERVAL`,
+//! `BYMONTH`, `BYMONTHDAY` (negative counts from the month's end), `BYDAY` (`SA`..`FR`,
+//! with an optional ordinal such as `2WE` or `-1FR` in monthly and yearly rules),
+//! `BYSETPOS`, `COUNT`, and `UNTIL` (a Parsi date). Without `BYDAY` or `BYMONTHDAY`, a
+//! rule repeats on the start date's weekday or day of month, and a yearly rule on its
+//! month too; months without that day (e.g. 31 in Mehr) are skipped, as RFC 5545 does.
+//! Unlike RFC 5545, `BYDAY` in a yearly rule counts within each month of `BYMONTH`.
+
+use crate::utils::{parse_input_datetime_or_date, to_ascii_digits};
+use crate::weekday::{self, Weekday};
+use anyhow::{Context, Result, bail};
+use parsidate::ParsiDate;
+use std::collections::VecDeque;
+
+/// RRULE weekday codes, in Saturday-first order to match `Weekday::number`.
+const WEEKDAY_CODES: [&str; 7] = ["SA", "SU", "MO", "TU", "WE", "TH", "FR"];
+
+/// How often a rule repeats.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Frequency {
+    Daily,
+    Weekly,
+    Monthly,
+    Yearly,
+}
+
+/// A `BYDAY` entry: a weekday, optionally the `n`-th of its month (negative from the end).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct WeekdaySpec {
+    pub ordinal: Option<i32>,
+    pub weekday: Weekday,
+}
+
+/// A parsed recurrence rule.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RecurrenceRule {
+    pub frequency: Frequency,
+    /// Repeat every `interval` days, weeks, months, or years.
+    pub interval: u32,
+    pub by_month: Vec<u32>,
+    pub by_month_day: Vec<i32>,
+    pub by_day: Vec<WeekdaySpec>,
+    /// Keep only these positions (1-based, negative from the end) of each period's dates.
+    pub by_set_pos: Vec<i32>,
+    /// Stop after this many occurrences.
+    pub count: Option<u32>,
+    /// Stop after this date (inclusive).
+    pub until: Option<ParsiDate>,
+}
+
+impl RecurrenceRule {
+    /// Parses a rule such as `FREQ=MONTHLY;BYDAY=-1FR` (see the module documentation).
+    /// Part names are case-insensitive, and an `RRULE:` prefix is allowed.
+    pub fn parse(input: &str) -> Result<RecurrenceRule> {
+        let normalized = to_ascii_digits(input.trim()).to_uppercase();
+        let body = normalized.strip_prefix("RRULE:").unwrap_or(&normalized);
+
+        let mut frequency = None;
+        let mut rule = RecurrenceRule {
+            frequency: Frequency::Daily,
+            interval: 1,
+            by_month: Vec::new(),
+            by_month_day: Vec::new(),
+            by_day: Vec::new(),
+            by_set_pos: Vec::new(),
+            count: None,
+            until: None,
+        };
+        for part in body.split(';').filter(|part| !part.is_empty()) {
+            let Some((name, value)) = part.split_once('=') else {
+                bail!(
+                    "Error: Invalid rule part '{}' in '{}'. Expected NAME=VALUE.",
+                    part,
+                    input.trim()
+                );
+            };
+            let invalid = || format!("Error: Invalid {} value '{}'.", name, value);
+            match name {
+                "FREQ" => {
+                    frequency = Some(match value {
+                        "DAILY" => Frequency::Daily,
+                        "WEEKLY" => Frequency::Weekly,
+                        "MONTHLY" => Frequency::Monthly,
+                        "YEARLY" => Frequency::Yearly,
+                        _ => bail!(
+                            "Error: Unsupported FREQ '{}'. Use DAILY, WEEKLY, MONTHLY, or YEARLY.",
+                            value
+                        ),
+                    })
+                }
+                "INTERVAL" => {
+                    rule.interval = match value.parse() {
+                        Ok(interval) if interval > 0 => interval,
+                        _ => bail!(invalid()),
+                    }
+                }
+                "BYMONTH" => {
+                    rule.by_month = parse_numbers(value, |m: &u32| (1..=12).contains(m))
+                        .with_context(invalid)?
+                }
+                "BYMONTHDAY" => {
+                    rule.by_month_day =
+                        parse_numbers(value, |d: &i32| *d != 0 && (-31..=31).contains(d))
+                            .with_context(invalid)?
+                }
+                "BYDAY" => {
+                    rule.by_day = value
+                        .split(',')
+                        .map(parse_weekday_spec)
+                        .collect::<Option<Vec<_>>>()
+                        .with_context(invalid)?
+                }
+                "BYSETPOS" => {
+                    rule.by_set_pos =
+                        parse_numbers(value, |p: &i32| *p != 0 && (-366..=366).contains(p))
+                            .with_context(invalid)?
+                }
+                "COUNT" => rule.count = Some(value.parse().ok().with_context(invalid)?),
+                "UNTIL" => {
+                    rule.until = Some(
+                        parse_input_datetime_or_date(value)
+                            .with_context(invalid)?
+                            .0
+                            .date(),
+                    )
+                }
+                _ => bail!("Error: Unsupported rule part '{}'.", name),
+            }
+        }
+
+        rule.frequency = frequency.with_context(|| {
+            format!(
+                "Error: The rule '{}' has no FREQ (e.g., FREQ=MONTHLY).",
+                input.trim()
+            )
+        })?;
+        if rule.by_day.iter().any(|spec| spec.ordinal.is_some())
+            && !matches!(rule.frequency, Frequency::Monthly | Frequency::Yearly)
+        {
+            bail!(
+                "Error: BYDAY ordinals such as -1FR are only allowed in MONTHLY and YEARLY rules."
+            );
+        }
+        Ok(rule)
+    }
+
+    /// The occurrences of the rule from `start` (the first date of the series) through
+    /// `end`, inclusive, also bounded by `COUNT` and `UNTIL`.
+    pub fn occurrences(&self, start: ParsiDate, end: ParsiDate) -> Result<Occurrences<'_>> {
+        let end = self.until.map_or(end, |until| until.min(end));
+        Ok(Occurrences {
+            rule: self,
+            start,
+            start_weekday: weekday::weekday_number(&start)?,
+            end,
+            period: 0,
+            pending: VecDeque::new(),
+            emitted: 0,
+        })
+    }
+}
+
+/// Iterator over the dates of a `RecurrenceRule`, in order.
+///
+/// Like RFC 5545, it steps from period to period (every `INTERVAL` days, weeks, months,
+/// or years from the start's), expands `BYMONTHDAY` and `BYDAY` within each period,
+/// limits the candidates by the remaining parts, and then applies `BYSETPOS`.
+#[derive(Debug, Clone)]
+pub struct Occurrences<'a> {
+    rule: &'a RecurrenceRule,
+    start: ParsiDate,
+    /// Saturday-based weekday number of `start`.
+    start_weekday: u32,
+    /// The last date to consider, already bounded by `UNTIL`.
+    end: ParsiDate,
+    /// Index of the next period to expand (0 is the start's period).
+    period: u64,
+    /// Occurrences of the last expanded period not yet returned.
+    pending: VecDeque<ParsiDate>,
+    emitted: u32,
+}
+
+impl Occurrences<'_> {
+    /// The occurrences in the `period`-th period from `start` through `end`, or `None`
+    /// once the period begins after `end` or outside the supported range.
+    fn expand(&self, period: u64) -> Option<Vec<ParsiDate>> {
+        let rule = self.rule;
+        let step = i64::try_from(period.checked_mul(u64::from(rule.interval))?).ok()?;
+        let mut dates = match rule.frequency {
+            Frequency::Daily => {
+                let date = self.start.add_days(step).ok()?;
+                let weekday = (i64::from(self.start_weekday) + step).rem_euclid(7) as u32;
+                if date > self.end {
+                    return None;
+                }
+                if self.matches_limits(&date, weekday) {
+                    vec![date]
+                } else {
+                    Vec::new()
+                }
+            }
+            Frequency::Weekly => {
+                // Days from the start to the Saturday that begins this period's week.
+                let saturday = step * 7 - i64::from(self.start_weekday);
+                let first = if period == 0 {
+                    self.start
+                } else {
+                    self.start.add_days(saturday).ok()?
+                };
+                if first > self.end {
+                    return None;
+                }
+                let weekdays: Vec<u32> = if rule.by_day.is_empty() {
+                    vec![self.start_weekday]
+                } else {
+                    rule.by_day
+                        .iter()
+                        .map(|spec| spec.weekday.number())
+                        .collect()
+                };
+                weekdays
+                    .into_iter()
+                    .filter_map(|weekday| {
+                        let date = self.start.add_days(saturday + i64::from(weekday)).ok()?;
+                        self.matches_limits(&date, weekday).then_some(date)
+                    })
+                    .collect()
+            }
+            Frequency::Monthly => {
+                let months = i64::from(self.start.month()) - 1 + step;
+                let year = self.start.year() + i32::try_from(months / 12).ok()?;
+                let month = (months % 12) as u32 + 1;
+                if ParsiDate::new(year, month, 1).ok()? > self.end {
+                    return None;
+                }
+                if rule.by_month.is_empty() || rule.by_month.contains(&month) {
+                    self.month_dates(year, month)?
+                } else {
+                    Vec::new()
+                }
+            }
+            Frequency::Yearly => {
+                let year = self.start.year() + i32::try_from(step).ok()?;
+                if ParsiDate::new(year, 1, 1).ok()? > self.end {
+                    return None;
+                }
+                let months = if rule.by_month.is_empty() {
+                    vec![self.start.month()]
+                } else {
+                    rule.by_month.clone()
+                };
+                let mut dates = Vec::new();
+                for month in months {
+                    dates.extend(self.month_dates(year, month)?);
+                }
+                dates
+            }
+        };
+
+        dates.sort();
+        dates.dedup();
+        if !rule.by_set_pos.is_empty() {
+            let len = dates.len() as i32;
+            let mut selected: Vec<ParsiDate> = rule
+                .by_set_pos
+                .iter()
+                .filter_map(|&pos| {
+                    let index = if pos > 0 { pos - 1 } else { len + pos };
+                    dates.get(usize::try_from(index).ok()?).copied()
+                })
+                .collect();
+            selected.sort();
+            selected.dedup();
+            dates = selected;
+        }
+        dates.retain(|date| *date >= self.start && *date <= self.end);
+        Some(dates)
+    }
+
+    /// The candidates in a month of a monthly or yearly rule: the `BYMONTHDAY` days,
+    /// limited to (or without them, expanded from) `BYDAY`, or else the start's day.
+    fn month_dates(&self, year: i32, month: u32) -> Option<Vec<ParsiDate>> {
+        let rule = self.rule;
+        let days_in_month = ParsiDate::days_in_month(year, month) as i32;
+        if rule.by_month_day.is_empty() && rule.by_day.is_empty() {
+            return Some(
+                ParsiDate::new(year, month, self.start.day())
+                    .ok()
+                    .into_iter()
+                    .collect(),
+            );
+        }
+
+        let first_weekday = weekday::weekday_number(&ParsiDate::new(year, month, 1).ok()?).ok()?;
+        let days: Vec<i32> = if rule.by_month_day.is_empty() {
+            (1..=days_in_month).collect()
+        } else {
+            rule.by_month_day
+                .iter()
+                .map(|&d| if d < 0 { days_in_month + 1 + d } else { d })
+                .filter(|d| (1..=days_in_month).contains(d))
+                .collect()
+        };
+        Some(
+            days.into_iter()
+                .filter(|&day| {
+                    let weekday = (first_weekday + day as u32 - 1) % 7;
+                    rule.by_day.is_empty()
+                        || rule.by_day.iter().any(|spec| {
+                            spec.weekday.number() == weekday
+                                && match spec.ordinal {
+                                    None => true,
+                                    Some(n) if n > 0 => (day - 1) / 7 + 1 == n,
+                                    Some(n) => (days_in_month - day) / 7 + 1 == -n,
+                                }
+                        })
+                })
+                .filter_map(|day| ParsiDate::new(year, month, day as u32).ok())
+                .collect(),
+        )
+    }
+
+    /// Whether a daily or weekly candidate passes `BYMONTH`, `BYMONTHDAY`, and (for daily
+    /// rules, where it limits rather than expands) `BYDAY`.
+    fn matches_limits(&self, date: &ParsiDate, weekday: u32) -> bool {
+        let rule = self.rule;
+        let days_in_month = ParsiDate::days_in_month(date.year(), date.month()) as i32;
+        (rule.by_month.is_empty() || rule.by_month.contains(&date.month()))
+            && (rule.by_month_day.is_empty()
+                || rule.by_month_day.iter().any(|&d| {
+                    let d = if d < 0 { days_in_month + 1 + d } else { d };
+                    d == date.day() as i32
+                }))
+            && (rule.by_day.is_empty()
+                || rule
+                    .by_day
+                    .iter()
+                    .any(|spec| spec.weekday.number() == weekday))
+    }
+}
+
+impl Iterator for Occurrences<'_> {
+    type Item = ParsiDate;
+
+    fn next(&mut self) -> Option<ParsiDate> {
+        if self.rule.count.is_some_and(|count| self.emitted >= count) {
+            return None;
+        }
+        while self.pending.is_empty() {
+            let dates = self.expand(self.period)?;
+            self.period += 1;
+            self.pending.extend(dates);
+        }
+        self.emitted += 1;
+        self.pending.pop_front()
+    }
+}
+
+/// Parses a comma-separated list of numbers, each accepted by `valid`.
+fn parse_numbers<T: std::str::FromStr>(value: &str, valid: impl Fn(&T) -> bool) -> Option<Vec<T>> {
+    value
+        .split(',')
+        .map(|n| n.trim().parse().ok().filter(&valid))
+        .collect()
+}
+
+/// Parses a `BYDAY` entry such as `WE`, `2WE`, or `-1FR`.
+fn parse_weekday_spec(value: &str) -> Option<WeekdaySpec> {
+    let value = value.trim();
+    if !value.is_ascii() {
+        return None;
+    }
+    let (ordinal, code) = value.split_at(value.len().checked_sub(2)?);
+    let number = WEEKDAY_CODES.iter().position(|c| *c == code)?;
+    let weekday = [
+        Weekday::Saturday,
+        Weekday::Sunday,
+        Weekday::Monday,
+        Weekday::Tuesday,
+        Weekday::Wednesday,
+        Weekday::Thursday,
+        Weekday::Friday,
+    ][number];
+    let ordinal = match ordinal.trim_start_matches('+') {
+        "" => None,
+        n => Some(
+            n.parse::<i32>()
+                .ok()
+                .filter(|n| *n != 0 && (-5..=5).contains(n))?,
+        ),
+    };
+    Some(WeekdaySpec { ordinal, weekday })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use parsidate::MAX_PARSI_DATE;
+
+    fn date(year: i32, month: u32, day: u32) -> ParsiDate {
+        ParsiDate::new(year, month, day).unwrap()
+    }
+
+    /// The rule's occurrences from `start` through `end`, formatted as `YYYY/MM/DD`.
+    fn dates(rule: &str, start: ParsiDate, end: ParsiDate) -> Vec<String> {
+        RecurrenceRule::parse(rule)
+            .unwrap()
+            .occurrences(start, end)
+            .unwrap()
+            .map(|d| d.to_string())
+            .collect()
+    }
+
+    fn parse_error(rule: &str) -> String {
+        RecurrenceRule::parse(rule).unwrap_err().to_string()
+    }
+
+    #[test]
+    fn parse_rejects_invalid_rules() {
+        assert!(parse_error("BYMONTH=1").contains("has no FREQ"));
+        assert!(parse_error("FREQ=HOURLY").contains("Unsupported FREQ 'HOURLY'"));
+        assert!(parse_error("FREQ=DAILY;INTERVAL=0").contains("Invalid INTERVAL value '0'"));
+        assert!(parse_error("FREQ=MONTHLY;BYMONTHDAY=0").contains("Invalid BYMONTHDAY"));
+        assert!(parse_error("FREQ=MONTHLY;BYMONTH=13").contains("Invalid BYMONTH"));
+        assert!(parse_error("FREQ=MONTHLY;BYDAY=6FR").contains("Invalid BYDAY"));
+        assert!(parse_error("FREQ=MONTHLY;BYSETPOS=0").contains("Invalid BYSETPOS"));
+        assert!(parse_error("FREQ=WEEKLY;BYDAY=-1FR").contains("only allowed in MONTHLY"));
+        assert!(parse_error("FREQ=DAILY;WKST=SA").contains("Unsupported rule part 'WKST'"));
+        assert!(parse_error("FREQ=DAILY;COUNT").contains("Expected NAME=VALUE"));
+    }
+
+    #[test]
+    fn parse_accepts_prefix_case_and_persian_digits() {
+        let rule = RecurrenceRule::parse("rrule:freq=monthly;bymonthday=-۱;count=۲").unwrap();
+        assert_eq!(rule.frequency, Frequency::Monthly);
+        assert_eq!(rule.by_month_day, vec![-1]);
+        assert_eq!(rule.count, Some(2));
+    }
+
+    #[test]
+    fn last_friday_of_each_month() {
+        assert_eq!(
+            dates(
+                "FREQ=MONTHLY;BYDAY=-1FR",
+                date(1404, 1, 1),
+                date(1404, 3, 31)
+            ),
+            ["1404/01/29", "1404/02/26", "1404/03/30"]
+        );
+    }
+
+    #[test]
+    fn month_day_31_skips_shorter_months() {
+        assert_eq!(
+            dates(
+                "FREQ=MONTHLY;BYMONTHDAY=31",
+                date(1404, 5, 1),
+                date(1404, 9, 30)
+            ),
+            ["1404/05/31", "1404/06/31"]
+        );
+        // Without BYMONTHDAY the start's day is used, so Mehr through Esfand are skipped.
+        assert_eq!(
+            dates("FREQ=MONTHLY;COUNT=3", date(1404, 6, 31), MAX_PARSI_DATE),
+            ["1404/06/31", "1405/01/31", "1405/02/31"]
+        );
+        // Likewise, a yearly rule on 30 Esfand only lands in leap years.
+        assert_eq!(
+            dates("FREQ=YEARLY", date(1403, 12, 30), date(1410, 1, 1)),
+            ["1403/12/30", "1408/12/30"]
+        );
+    }
+
+    #[test]
+    fn negative_month_day_counts_from_the_end() {
+        assert_eq!(
+            dates(
+                "FREQ=MONTHLY;BYMONTHDAY=-1",
+                date(1404, 6, 1),
+                date(1404, 12, 29)
+            ),
+            [
+                "1404/06/31",
+                "1404/07/30",
+                "1404/08/30",
+                "1404/09/30",
+                "1404/10/30",
+                "1404/11/30",
+                "1404/12/29"
+            ]
+        );
+    }
+
+    #[test]
+    fn set_position_picks_within_each_period() {
+        // The last of the 29th-31st that exists, as the fiscal rules use for month-end dues.
+        assert_eq!(
+            dates(
+                "FREQ=MONTHLY;BYMONTHDAY=29,30,31;BYSETPOS=-1",
+                date(1404, 6, 1),
+                date(1404, 7, 30)
+            ),
+            ["1404/06/31", "1404/07/30"]
+        );
+        // The first day of each month that is not a Friday.
+        assert_eq!(
+            dates(
+                "FREQ=MONTHLY;BYDAY=SA,SU,MO,TU,WE,TH;BYSETPOS=1",
+                date(1404, 1, 1),
+                date(1404, 2, 31)
+            ),
+            ["1404/01/02", "1404/02/01"]
+        );
+    }
+
+    #[test]
+    fn interval_steps_by_period() {
+        assert_eq!(
+            dates(
+                "FREQ=WEEKLY;INTERVAL=2;BYDAY=WE;COUNT=4",
+                date(1404, 1, 1),
+                MAX_PARSI_DATE
+            ),
+            ["1404/01/13", "1404/01/27", "1404/02/10", "1404/02/24"]
+        );
+        assert_eq!(
+            dates("FREQ=YEARLY;INTERVAL=2", date(1403, 1, 1), date(1408, 1, 1)),
+            ["1403/01/01", "1405/01/01", "1407/01/01"]
+        );
+    }
+
+    #[test]
+    fn count_and_until_end_the_series() {
+        assert_eq!(
+            dates("FREQ=DAILY;COUNT=3", date(1404, 12, 28), MAX_PARSI_DATE),
+            ["1404/12/28", "1404/12/29", "1405/01/01"]
+        );
+        assert_eq!(
+            dates(
+                "FREQ=WEEKLY;UNTIL=1404/01/15",
+                date(1404, 1, 1),
+                MAX_PARSI_DATE
+            ),
+            ["1404/01/01", "1404/01/08", "1404/01/15"]
+        );
+        // The earlier of UNTIL and the requested end wins.
+        assert_eq!(
+            dates(
+                "FREQ=WEEKLY;UNTIL=1404/01/15",
+                date(1404, 1, 1),
+                date(1404, 1, 10)
+            ),
+            ["1404/01/01", "1404/01/08"]
+        );
+        // The series also ends quietly at the last supported date.
+        assert_eq!(
+            dates("FREQ=YEARLY", date(9998, 1, 1), MAX_PARSI_DATE),
+            ["9998/01/01", "9999/01/01"]
+        );
+    }
+}
@@ -0,0 +1,54 @@
+//  ~/src/rfc3339.rs
+//
+//  * Copyright (C) 2024–2025 Parsicore <parsicore.dev@gmail.com>
+//  * Package : mitra
+//  * License : Apache-2.0
+//  * Version : 2.3.0
+//  * URL     : https://github.com/parsicore/Mitra
+//  * Sign: mitra-20250419-bd5fbe728fa2-5836b45f25d83501625cc5529193d5f0
+//
+//! RFC 3339 (the internet profile of ISO 8601) timestamps such as
+//! `2025-03-16T09:30:00+03:30` or `2025-03-16T06:00:00Z`. They are always Gregorian and
+//! always carry a UTC offset, so a Parsi datetime is paired with a `FixedOffset` here:
+//! the Parsi wall-clock time at that offset.
+//!
+//! Parsi datetimes have whole seconds, so fractional seconds are accepted and dropped.
+
+use crate::utils::map_mitra_error;
+use anyhow::{Context, Result};
+use chrono::{DateTime, FixedOffset, SecondsFormat, TimeZone, Utc};
+use parsidate::ParsiDateTime;
+
+/// Parses an RFC 3339 timestamp into its Parsi wall-clock datetime and UTC offset.
+/// The date and time may be separated by `T` or a space, and UTC may be written `Z`.
+pub fn parse_rfc3339(input: &str) -> Result<(ParsiDateTime, FixedOffset)> {
+    let timestamp = DateTime::parse_from_rfc3339(input.trim()).with_context(|| {
+        format!(
+            "Error: '{}' is not an RFC 3339 timestamp (e.g., 2025-03-16T09:30:00+03:30).",
+            input.trim()
+        )
+    })?;
+    let datetime = ParsiDateTime::from_gregorian(timestamp.naive_local())
+        .map_err(|e| map_mitra_error(e, "converting to Parsi"))?;
+    Ok((datetime, *timestamp.offset()))
+}
+
+/// The instant of a Parsi wall-clock datetime at a fixed UTC offset.
+pub fn to_utc(datetime: &ParsiDateTime, offset: FixedOffset) -> Result<DateTime<Utc>> {
+    let naive = datetime
+        .to_gregorian()
+        .map_err(|e| map_mitra_error(e, "converting to Gregorian"))?;
+    offset
+        .from_local_datetime(&naive)
+        .single()
+        .map(|instant| instant.with_timezone(&Utc))
+        .with_context(|| format!("Error: {} is out of range at offset {}.", datetime, offset))
+}
+
+/// Formats a Parsi wall-clock datetime at `offset` as an RFC 3339 timestamp, e.g.
+/// `2025-03-16T09:30:00+03:30`; a zero offset is written `Z`.
+pub fn format_rfc3339(datetime: &ParsiDateTime, offset: FixedOffset) -> Result<String> {
+    Ok(to_utc(datetime, offset)?
+        .with_timezone(&offset)
+        .to_rfc3339_opts(SecondsFormat::Secs, true))
+}
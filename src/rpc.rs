@@ -0,0 +1,287 @@
+//  ~/src/rpc.rs
+//
+//  * Copyright (C) 2024–2025 Parsicore <parsicore.dev@gmail.com>
+//  * Package : mitra
+//  * License : Apache-2.0
+//  * Version : 2.3.0
+//  * URL     : https://github.com/parsicore/Mitra
+//  * Sign: mitra-20250419-bd5fbe728fa2-5836b45f25d83501625cc5529193d5f0
+//
+//! A JSON-RPC 2.0 server speaking newline-delimited JSON over stdin/stdout,
+//! so editors and other long-lived processes can keep one Mitra instance warm.
+//!
+//! Each input line holds one request (or a batch array) and gets one response line.
+//! Parameters are passed by name. Supported methods:
+//!
+//! | Method           | Params                                      | Result                       |
+//! |------------------|---------------------------------------------|------------------------------|
+//! | `to_gregorian`   | `date`                                      | Gregorian date string        |
+//! | `from_gregorian` | `date`                                      | Parsi date string            |
+//! | `add`            | `date`, one of `days` / `months` / `years`  | Parsi date string            |
+//! | `diff`           | `from`, `to`                                | absolute number of days      |
+//! | `weekday`        | `date`                                      | Persian weekday name         |
+//! | `is_leap`        | `year`                                      | boolean                      |
+//! | `info`           | `date`                                      | same object as `info --json` |
+//! | `events`         | `date`                                      | array of events              |
+
+use crate::events;
+use crate::info::DateInfo;
+use crate::utils::{
+    check_supported_year, map_mitra_error, parse_gregorian_input, parse_input_datetime_or_date,
+};
+use anyhow::{Context, Result};
+use parsidate::{ParsiDate, ParsiDateTime};
+use serde_json::{Map, Value, json};
+use std::io::{BufRead, Write};
+
+// Standard JSON-RPC 2.0 error codes.
+const PARSE_ERROR: i64 = -32700;
+const INVALID_REQUEST: i64 = -32600;
+const METHOD_NOT_FOUND: i64 = -32601;
+const INVALID_PARAMS: i64 = -32602;
+/// Server-defined code for date errors (unparsable input, out-of-range results, ...).
+const DATE_ERROR: i64 = -32000;
+
+/// A JSON-RPC error object.
+struct RpcError {
+    code: i64,
+    message: String,
+}
+
+impl RpcError {
+    fn new(code: i64, message: impl Into<String>) -> Self {
+        RpcError {
+            code,
+            message: message.into(),
+        }
+    }
+}
+
+impl From<anyhow::Error> for RpcError {
+    fn from(err: anyhow::Error) -> Self {
+        // Use the full context chain, as the CLI would print it.
+        RpcError::new(DATE_ERROR, format!("{:#}", err))
+    }
+}
+
+/// Reads requests from `reader` line by line until EOF, writing one response line per request.
+/// Blank lines and notifications (requests without an `id`) produce no output.
+pub fn serve<R: BufRead, W: Write>(reader: R, mut writer: W) -> Result<()> {
+    for line in reader.lines() {
+        let line = line.context("Failed to read request from stdin")?;
+        if line.trim().is_empty() {
+            continue;
+        }
+        if let Some(response) = handle_line(&line) {
+            writeln!(writer, "{}", response).context("Failed to write response")?;
+            writer.flush().context("Failed to flush response")?;
+        }
+    }
+    Ok(())
+}
+
+/// Handles a single input line, returning the serialized response (if any).
+fn handle_line(line: &str) -> Option<String> {
+    let request: Value = match serde_json::from_str(line) {
+        Ok(value) => value,
+        Err(e) => {
+            let error = RpcError::new(PARSE_ERROR, format!("Parse error: {}", e));
+            return Some(error_response(Value::Null, error).to_string());
+        }
+    };
+
+    match request {
+        Value::Array(batch) if batch.is_empty() => Some(
+            error_response(
+                Value::Null,
+                RpcError::new(INVALID_REQUEST, "Invalid Request: empty batch"),
+            )
+            .to_string(),
+        ),
+        Value::Array(batch) => {
+            let responses: Vec<Value> = batch.into_iter().filter_map(handle_request).collect();
+            // A batch made only of notifications gets no response at all.
+            (!responses.is_empty()).then(|| Value::Array(responses).to_string())
+        }
+        single => handle_request(single).map(|response| response.to_string()),
+    }
+}
+
+/// Handles one request object. Returns `None` for notifications.
+fn handle_request(request: Value) -> Option<Value> {
+    let Value::Object(mut request) = request else {
+        return Some(error_response(
+            Value::Null,
+            RpcError::new(INVALID_REQUEST, "Invalid Request: expected an object"),
+        ));
+    };
+
+    let id = request.remove("id");
+    let is_notification = id.is_none();
+    let id = id.unwrap_or(Value::Null);
+
+    if request.get("jsonrpc").and_then(Value::as_str) != Some("2.0") {
+        let error = RpcError::new(INVALID_REQUEST, "Invalid Request: jsonrpc must be \"2.0\"");
+        return Some(error_response(id, error));
+    }
+    let Some(method) = request.get("method").and_then(Value::as_str) else {
+        let error = RpcError::new(INVALID_REQUEST, "Invalid Request: missing method");
+        return Some(error_response(id, error));
+    };
+    let params = match request.get("params") {
+        None | Some(Value::Null) => Map::new(),
+        Some(Value::Object(params)) => params.clone(),
+        Some(_) => {
+            let error = RpcError::new(INVALID_PARAMS, "Invalid params: expected named parameters");
+            return (!is_notification).then(|| error_response(id, error));
+        }
+    };
+
+    log::debug!("RPC call: {} {:?}", method, params);
+    let outcome = dispatch(method, &params);
+    if is_notification {
+        return None;
+    }
+    Some(match outcome {
+        Ok(result) => json!({ "jsonrpc": "2.0", "result": result, "id": id }),
+        Err(error) => error_response(id, error),
+    })
+}
+
+/// Builds a JSON-RPC error response.
+fn error_response(id: Value, error: RpcError) -> Value {
+    json!({
+        "jsonrpc": "2.0",
+        "error": { "code": error.code, "message": error.message },
+        "id": id,
+    })
+}
+
+/// Runs a method with its named parameters.
+fn dispatch(method: &str, params: &Map<String, Value>) -> Result<Value, RpcError> {
+    match method {
+        "to_gregorian" => {
+            let (pdt, was_datetime) = parse_input_datetime_or_date(str_param(params, "date")?)?;
+            let gregorian = pdt
+                .to_gregorian()
+                .map_err(|e| map_mitra_error(e, "converting to Gregorian"))?;
+            let pattern = if was_datetime {
+                "%Y-%m-%d %H:%M:%S"
+            } else {
+                "%Y-%m-%d"
+            };
+            Ok(json!(gregorian.format(pattern).to_string()))
+        }
+        "from_gregorian" => {
+            let (ndt, was_datetime) = parse_gregorian_input(str_param(params, "date")?)?;
+            let pdt = ParsiDateTime::from_gregorian(ndt)
+                .map_err(|e| map_mitra_error(e, "converting from Gregorian"))?;
+            Ok(json!(format_result(&pdt, was_datetime)))
+        }
+        "add" => {
+            let (pdt, was_datetime) = parse_input_datetime_or_date(str_param(params, "date")?)?;
+            let units = ["days", "months", "years"];
+            let given: Vec<&str> = units
+                .into_iter()
+                .filter(|unit| params.contains_key(*unit))
+                .collect();
+            let [unit] = given.as_slice() else {
+                return Err(RpcError::new(
+                    INVALID_PARAMS,
+                    "Invalid params: specify exactly one of days, months, or years",
+                ));
+            };
+            let amount = int_param(params, unit)?;
+            let result = match *unit {
+                "days" => pdt
+                    .add_days(amount)
+                    .map_err(|e| map_mitra_error(e, "adding days")),
+                "months" => pdt
+                    .add_months(i32_amount(amount, unit)?)
+                    .map_err(|e| map_mitra_error(e, "adding months")),
+                _ => pdt
+                    .add_years(i32_amount(amount, unit)?)
+                    .map_err(|e| map_mitra_error(e, "adding years")),
+            }?;
+            Ok(json!(format_result(&result, was_datetime)))
+        }
+        "diff" => {
+            let (from, _) = parse_input_datetime_or_date(str_param(params, "from")?)?;
+            let (to, _) = parse_input_datetime_or_date(str_param(params, "to")?)?;
+            let days = from
+                .date()
+                .days_between(&to.date())
+                .map_err(|e| map_mitra_error(e, "calculating date difference"))?;
+            Ok(json!(days))
+        }
+        "weekday" => {
+            let (pdt, _) = parse_input_datetime_or_date(str_param(params, "date")?)?;
+            let weekday = pdt
+                .date()
+                .weekday()
+                .map_err(|e| map_mitra_error(e, "getting weekday"))?;
+            Ok(json!(weekday))
+        }
+        "is_leap" => {
+            let year = i32_amount(int_param(params, "year")?, "year")?;
+            check_supported_year(year)?;
+            Ok(json!(ParsiDate::is_persian_leap_year(year)))
+        }
+        "info" => {
+            let input = str_param(params, "date")?;
+            let (pdt, was_datetime) = parse_input_datetime_or_date(input)?;
+            let info = DateInfo::new(input, &pdt, was_datetime)?;
+            Ok(serde_json::to_value(info).context("Failed to serialize date info")?)
+        }
+        "events" => {
+            let (pdt, _) = parse_input_datetime_or_date(str_param(params, "date")?)?;
+            let date = pdt.date();
+            let day_events = events::get_events_for_date(date.year(), date.month(), date.day())
+                .unwrap_or_default();
+            Ok(serde_json::to_value(day_events).context("Failed to serialize events")?)
+        }
+        other => Err(RpcError::new(
+            METHOD_NOT_FOUND,
+            format!("Method not found: {}", other),
+        )),
+    }
+}
+
+/// Formats a Parsi result, dropping the time part if the input was a plain date.
+fn format_result(pdt: &ParsiDateTime, was_datetime: bool) -> String {
+    if was_datetime {
+        pdt.to_string()
+    } else {
+        pdt.date().to_string()
+    }
+}
+
+/// Fetches a required string parameter.
+fn str_param<'a>(params: &'a Map<String, Value>, name: &str) -> Result<&'a str, RpcError> {
+    params.get(name).and_then(Value::as_str).ok_or_else(|| {
+        RpcError::new(
+            INVALID_PARAMS,
+            format!("Invalid params: '{}' must be a string", name),
+        )
+    })
+}
+
+/// Fetches a required integer parameter.
+fn int_param(params: &Map<String, Value>, name: &str) -> Result<i64, RpcError> {
+    params.get(name).and_then(Value::as_i64).ok_or_else(|| {
+        RpcError::new(
+            INVALID_PARAMS,
+            format!("Invalid params: '{}' must be an integer", name),
+        )
+    })
+}
+
+/// Narrows an integer parameter to `i32`.
+fn i32_amount(value: i64, name: &str) -> Result<i32, RpcError> {
+    i32::try_from(value).map_err(|_| {
+        RpcError::new(
+            INVALID_PARAMS,
+            format!("Invalid params: '{}' is out of range", name),
+        )
+    })
+}
@@ -0,0 +1,280 @@
+//  ~/src/schedule.rs
+//
+//  * Copyright (C) 2024–2025 Parsicore <parsicore.dev@gmail.com>
+//  * Package : mitra
+//  * License : Apache-2.0
+//  * Version : 2.3.0
+//  * URL     : https://github.com/parsicore/Mitra
+//  * Sign: mitra-20250419-bd5fbe728fa2-5836b45f25d83501625cc5529193d5f0
+//
+//! Parsi-calendar schedule expressions for the `schedule` command, covering what cron
+//! cannot express: Jalali month boundaries and working days.
+//!
+//! An expression is `YEAR-MONTH-DAY [HH:MM[:SS]]` (`/` also separates the date fields,
+//! and the time defaults to 00:00). YEAR and MONTH are `*` or comma-separated numbers.
+//! DAY is `*`, comma-separated numbers, `last` (the month's last day), `first-workday`,
+//! or `last-workday` (the first/last day of the month that is neither a Friday nor an
+//! official holiday). Examples: `1403-*-01 02:00`, `*-1,4,7,10-first-workday 09:00`,
+//! `*-*-last 23:30`.
+
+use crate::business_days;
+use crate::clock;
+use crate::utils::{map_mitra_error, to_ascii_digits};
+use anyhow::{Context, Result, bail};
+use parsidate::{ParsiDate, ParsiDateTime};
+use std::time::Duration;
+
+/// How far ahead to look for the next matching day before giving up.
+const SEARCH_YEARS: i32 = 10;
+
+/// Longest single sleep while waiting, so clock changes and suspends are noticed.
+const MAX_SLEEP: Duration = Duration::from_secs(30);
+
+/// Environment variable holding the scheduled time, set for each executed command.
+const SCHEDULED_AT_ENV: &str = "MITRA_SCHEDULED_AT";
+
+/// The day-of-month part of a schedule.
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum DaySpec {
+    Any,
+    Days(Vec<u32>),
+    Last,
+    FirstWorkday,
+    LastWorkday,
+}
+
+/// A parsed schedule expression.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Schedule {
+    /// Allowed years, or `None` for any.
+    years: Option<Vec<i32>>,
+    /// Allowed months, or `None` for any.
+    months: Option<Vec<u32>>,
+    day: DaySpec,
+    hour: u32,
+    minute: u32,
+    second: u32,
+}
+
+impl Schedule {
+    /// Parses a schedule expression (see the module documentation).
+    pub fn parse(expression: &str) -> Result<Schedule> {
+        let normalized = to_ascii_digits(expression.trim());
+        let invalid = || {
+            format!(
+                "Error: Invalid schedule '{}'. Expected YEAR-MONTH-DAY [HH:MM], e.g. \"1403-*-01 02:00\" or \"*-*-last 23:00\".",
+                expression.trim()
+            )
+        };
+
+        let mut parts = normalized.split_whitespace();
+        let date_part = parts.next().with_context(invalid)?;
+        let time_part = parts.next();
+        if parts.next().is_some() {
+            bail!(invalid());
+        }
+
+        // The day field may itself contain '-' (first-workday), so split at most twice.
+        let mut fields = date_part.splitn(3, ['-', '/']);
+        let (Some(year), Some(month), Some(day)) = (fields.next(), fields.next(), fields.next())
+        else {
+            bail!(invalid());
+        };
+
+        let years = parse_list(year, 1, 9999).with_context(invalid)?;
+        let months = parse_list(month, 1, 12).with_context(invalid)?;
+        let day = match day.to_lowercase().as_str() {
+            "last" => DaySpec::Last,
+            "first-workday" => DaySpec::FirstWorkday,
+            "last-workday" => DaySpec::LastWorkday,
+            other => match parse_list(other, 1, 31).with_context(invalid)? {
+                None => DaySpec::Any,
+                Some(days) => DaySpec::Days(days.into_iter().map(|d| d as u32).collect()),
+            },
+        };
+
+        let (hour, minute, second) = match time_part {
+            None => (0, 0, 0),
+            Some(time) => {
+                let numbers = time
+                    .split(':')
+                    .map(|n| n.parse::<u32>())
+                    .collect::<Result<Vec<_>, _>>()
+                    .with_context(invalid)?;
+                match numbers[..] {
+                    [h, m] if h < 24 && m < 60 => (h, m, 0),
+                    [h, m, s] if h < 24 && m < 60 && s < 60 => (h, m, s),
+                    _ => bail!(invalid()),
+                }
+            }
+        };
+
+        Ok(Schedule {
+            years: years.map(|ys| ys.into_iter().map(|y| y as i32).collect()),
+            months: months.map(|ms| ms.into_iter().map(|m| m as u32).collect()),
+            day,
+            hour,
+            minute,
+            second,
+        })
+    }
+
+    /// Returns the first time strictly after `after` that matches the schedule, or `None`
+    /// if there is none within the next `SEARCH_YEARS` years (or the supported range).
+    pub fn next_after(&self, after: &ParsiDateTime) -> Result<Option<ParsiDateTime>> {
+        let mut date = after.date();
+        let last_year = (after.year() + SEARCH_YEARS).min(
+            self.years
+                .as_ref()
+                .and_then(|ys| ys.iter().max().copied())
+                .unwrap_or(i32::MAX),
+        );
+        while date.year() <= last_year {
+            if self.matches_date(&date)? {
+                let candidate = ParsiDateTime::new(
+                    date.year(),
+                    date.month(),
+                    date.day(),
+                    self.hour,
+                    self.minute,
+                    self.second,
+                )
+                .map_err(|e| map_mitra_error(e, "building scheduled time"))?;
+                if candidate > *after {
+                    return Ok(Some(candidate));
+                }
+            }
+            date = match date.add_days(1) {
+                Ok(next) => next,
+                // The end of the supported range.
+                Err(_) => break,
+            };
+        }
+        Ok(None)
+    }
+
+    fn matches_date(&self, date: &ParsiDate) -> Result<bool> {
+        if self
+            .years
+            .as_ref()
+            .is_some_and(|ys| !ys.contains(&date.year()))
+        {
+            return Ok(false);
+        }
+        if self
+            .months
+            .as_ref()
+            .is_some_and(|ms| !ms.contains(&date.month()))
+        {
+            return Ok(false);
+        }
+        Ok(match &self.day {
+            DaySpec::Any => true,
+            DaySpec::Days(days) => days.contains(&date.day()),
+            DaySpec::Last => *date == date.last_day_of_month(),
+            DaySpec::FirstWorkday => {
+                *date == business_days::next_business_day(&date.first_day_of_month())?
+            }
+            DaySpec::LastWorkday => {
+                business_days::is_business_day(date)?
+                    && !has_business_day_between(date, &date.last_day_of_month())?
+            }
+        })
+    }
+}
+
+/// Runs `command` through the shell at every time matching `schedule`, forever.
+/// Runs are sequential; a run that is missed (e.g., during a suspend) fires once when
+/// noticed. A failing command is logged and does not stop the daemon. Returns once a
+/// schedule with a fixed year has no runs left.
+pub fn run_daemon(schedule: &Schedule, command: &str) -> Result<()> {
+    let mut ran = false;
+    loop {
+        let now = clock::now()?;
+        let Some(target) = schedule.next_after(&now)? else {
+            if ran {
+                log::info!("No runs left in the schedule");
+                return Ok(());
+            }
+            bail!(
+                "Error: The schedule has no run within the next {} years.",
+                SEARCH_YEARS
+            );
+        };
+        log::info!("Next run at {}", target);
+        wait_until(&target)?;
+
+        log::info!("Running scheduled command for {}", target);
+        match shell(command)
+            .env(SCHEDULED_AT_ENV, target.to_string())
+            .status()
+        {
+            Ok(status) if status.success() => {}
+            Ok(status) => log::warn!("Scheduled command for {} failed: {}", target, status),
+            Err(e) => log::warn!("Could not start scheduled command for {}: {}", target, e),
+        }
+        ran = true;
+    }
+}
+
+/// Sleeps until the clock reaches `target`.
+fn wait_until(target: &ParsiDateTime) -> Result<()> {
+    let target_gregorian = target
+        .to_gregorian()
+        .map_err(|e| map_mitra_error(e, "converting scheduled time"))?;
+    loop {
+        let now = clock::now()?
+            .to_gregorian()
+            .map_err(|e| map_mitra_error(e, "converting current time"))?;
+        let Ok(remaining) = (target_gregorian - now).to_std() else {
+            return Ok(());
+        };
+        if remaining.is_zero() {
+            return Ok(());
+        }
+        std::thread::sleep(remaining.min(MAX_SLEEP));
+    }
+}
+
+/// A command that runs `command` through the platform shell.
+fn shell(command: &str) -> std::process::Command {
+    if cfg!(windows) {
+        let mut cmd = std::process::Command::new("cmd");
+        cmd.arg("/C").arg(command);
+        cmd
+    } else {
+        let mut cmd = std::process::Command::new("sh");
+        cmd.arg("-c").arg(command);
+        cmd
+    }
+}
+
+/// Whether any day after `date`, up to and including `last`, is a business day.
+fn has_business_day_between(date: &ParsiDate, last: &ParsiDate) -> Result<bool> {
+    let mut day = *date;
+    while day < *last {
+        day = day
+            .add_days(1)
+            .map_err(|e| map_mitra_error(e, "scanning the month"))?;
+        if business_days::is_business_day(&day)? {
+            return Ok(true);
+        }
+    }
+    Ok(false)
+}
+
+/// Parses `*` (any) or a comma-separated list of numbers within `min..=max`.
+fn parse_list(field: &str, min: i64, max: i64) -> Result<Option<Vec<i64>>> {
+    if field == "*" {
+        return Ok(None);
+    }
+    let mut values = Vec::new();
+    for item in field.split(',') {
+        let value: i64 = item.parse()?;
+        if !(min..=max).contains(&value) {
+            bail!("{} is out of range ({}-{})", value, min, max);
+        }
+        values.push(value);
+    }
+    Ok(Some(values))
+}
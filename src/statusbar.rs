@@ -0,0 +1,110 @@
+//  ~/src/statusbar.rs
+//
+//  * Copyright (C) 2024–2025 Parsicore <parsicore.dev@gmail.com>
+//  * Package : mitra
+//  * License : Apache-2.0
+//  * Version : 2.3.0
+//  * URL     : https://github.com/parsicore/Mitra
+//  * Sign: mitra-20250419-bd5fbe728fa2-5836b45f25d83501625cc5529193d5f0
+//
+//! Renders the single status line printed by the `statusbar` command for
+//! Waybar, Polybar, i3blocks, and similar status bars.
+//!
+//! The line is built from a user template with `{placeholder}` fields:
+//! `{date}`, `{weekday}`, `{day}`, `{month}`, `{month_name}`, `{year}`,
+//! `{gregorian}`, and `{next_event}`.
+
+use crate::events::{self, NEXT_EVENT_WINDOW_DAYS};
+use crate::utils::{humanize_day_span, map_mitra_error, signed_days_between};
+use anyhow::{Result, bail};
+use parsidate::ParsiDate;
+use serde::Serialize;
+
+/// Waybar's custom-module JSON format (`"return-type": "json"`).
+#[derive(Serialize, Debug)]
+pub struct WaybarOutput {
+    pub text: String,
+    pub tooltip: String,
+    /// CSS class: "holiday" on holidays, otherwise "normal".
+    pub class: String,
+}
+
+/// Expands `template` for the given date.
+/// Unknown placeholders are rejected so typos don't silently end up in the bar.
+pub fn render_line(today: &ParsiDate, template: &str) -> Result<String> {
+    let mut output = String::with_capacity(template.len());
+    let mut rest = template;
+    while let Some(start) = rest.find('{') {
+        output.push_str(&rest[..start]);
+        let after = &rest[start + 1..];
+        let Some(end) = after.find('}') else {
+            bail!("Error: Unclosed '{{' in status bar format '{}'.", template);
+        };
+        output.push_str(&placeholder_value(today, &after[..end])?);
+        rest = &after[end + 1..];
+    }
+    output.push_str(rest);
+    Ok(output)
+}
+
+/// Builds the Waybar JSON output: the rendered line as text, and today's events as tooltip.
+pub fn waybar_output(today: &ParsiDate, template: &str) -> Result<WaybarOutput> {
+    let day_events =
+        events::get_events_for_date(today.year(), today.month(), today.day()).unwrap_or_default();
+    let mut tooltip = today.format("%A %d %B %Y");
+    for event in &day_events {
+        tooltip.push('\n');
+        tooltip.push_str(&event.title);
+    }
+
+    Ok(WaybarOutput {
+        text: render_line(today, template)?,
+        tooltip,
+        class: if day_events.iter().any(|e| e.holiday) {
+            "holiday".to_string()
+        } else {
+            "normal".to_string()
+        },
+    })
+}
+
+/// Returns the value for a single `{name}` placeholder.
+fn placeholder_value(today: &ParsiDate, name: &str) -> Result<String> {
+    Ok(match name {
+        "date" => today.to_string(),
+        "weekday" => today
+            .weekday()
+            .map_err(|e| map_mitra_error(e, "getting weekday"))?,
+        "day" => today.day().to_string(),
+        "month" => today.month().to_string(),
+        "month_name" => today.format("%B"),
+        "year" => today.year().to_string(),
+        "gregorian" => today
+            .to_gregorian()
+            .map_err(|e| map_mitra_error(e, "converting to Gregorian"))?
+            .format("%Y-%m-%d")
+            .to_string(),
+        "next_event" => next_event_summary(today)?,
+        other => bail!(
+            "Error: Unknown placeholder '{{{}}}'. Expected one of: date, weekday, day, month, month_name, year, gregorian, next_event.",
+            other
+        ),
+    })
+}
+
+/// Describes the nearest upcoming event, e.g. "آغاز نوروز (۴۵ روز مانده)".
+/// Returns an empty string if nothing is found within the look-ahead window.
+fn next_event_summary(today: &ParsiDate) -> Result<String> {
+    let Some((date, day_events)) = events::next_events_from(today, NEXT_EVENT_WINDOW_DAYS) else {
+        return Ok(String::new());
+    };
+    // Prefer a holiday title when the day has one, since that's what people plan around.
+    let title = day_events
+        .iter()
+        .find(|e| e.holiday)
+        .or_else(|| day_events.first())
+        .map(|e| e.title.as_str())
+        .unwrap_or_default();
+    let days = signed_days_between(today, &date)?;
+    Ok(format!("{} ({})", title, humanize_day_span(days)))
+}
@@ -0,0 +1,152 @@
+//  ~/src/timezone.rs
+//
+//  * Copyright (C) 2024–2025 Parsicore <parsicore.dev@gmail.com>
+//  * Package : mitra
+//  * License : Apache-2.0
+//  * Version : 2.3.0
+//  * URL     : https://github.com/parsicore/Mitra
+//  * Sign: mitra-20250419-bd5fbe728fa2-5836b45f25d83501625cc5529193d5f0
+//
+//! Time-zone lookup on top of the IANA database bundled by `chrono-tz`. A zone can be
+//! named by its IANA identifier (`Asia/Tehran`, matched case-insensitively) or by the
+//! city part of one (`tehran`, `new york`, `new_york`).
+//!
+//! `ParsiZonedDateTime` pairs a Parsi datetime with its zone, for the `--tz` options
+//! of `now`, `to-gregorian`, and `from-gregorian`, and for Unix timestamp conversion.
+
+use crate::utils::map_mitra_error;
+use anyhow::{Result, bail};
+use chrono::{DateTime, FixedOffset, LocalResult, NaiveDateTime, Offset, TimeZone, Utc};
+use chrono_tz::{TZ_VARIANTS, Tz};
+use parsidate::ParsiDateTime;
+use std::fmt;
+
+/// Resolves a time-zone name or city to a zone.
+pub fn resolve(name: &str) -> Result<Tz> {
+    let wanted = name.trim().replace(' ', "_").to_lowercase();
+    if wanted.is_empty() {
+        bail!("Error: Empty time zone name.");
+    }
+    let by_id = TZ_VARIANTS
+        .iter()
+        .find(|tz| tz.name().to_lowercase() == wanted);
+    let by_city = || {
+        TZ_VARIANTS.iter().find(|tz| {
+            tz.name()
+                .rsplit('/')
+                .next()
+                .is_some_and(|city| city.to_lowercase() == wanted)
+        })
+    };
+    match by_id.or_else(by_city) {
+        Some(tz) => Ok(*tz),
+        None => bail!(
+            "Error: Unknown time zone or city '{}'. Use an IANA name such as Asia/Tehran or a city such as tehran.",
+            name.trim()
+        ),
+    }
+}
+
+/// A human-readable label for a zone: the city part of its IANA name, with spaces.
+pub fn city_label(tz: Tz) -> String {
+    tz.name()
+        .rsplit('/')
+        .next()
+        .unwrap_or(tz.name())
+        .replace('_', " ")
+}
+
+/// Interprets a wall-clock time in `tz`. Times repeated by a daylight-saving change
+/// resolve to the earlier instant; times skipped by one are an error.
+pub fn localize(tz: Tz, local: NaiveDateTime) -> Result<DateTime<Tz>> {
+    match tz.from_local_datetime(&local) {
+        LocalResult::Single(instant) => Ok(instant),
+        LocalResult::Ambiguous(earlier, _) => Ok(earlier),
+        LocalResult::None => bail!(
+            "Error: {} does not exist in {} (skipped by a daylight-saving change).",
+            local.format("%Y-%m-%d %H:%M:%S"),
+            tz.name()
+        ),
+    }
+}
+
+/// A Parsi wall-clock datetime in a specific time zone, i.e. a single instant.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParsiZonedDateTime {
+    datetime: ParsiDateTime,
+    local: DateTime<Tz>,
+}
+
+impl ParsiZonedDateTime {
+    /// The instant `utc` as Parsi wall-clock time in `tz`.
+    pub fn from_utc(utc: DateTime<Utc>, tz: Tz) -> Result<ParsiZonedDateTime> {
+        let local = utc.with_timezone(&tz);
+        let datetime = ParsiDateTime::from_gregorian(local.naive_local())
+            .map_err(|e| map_mitra_error(e, "converting to Parsi"))?;
+        Ok(ParsiZonedDateTime { datetime, local })
+    }
+
+    /// Interprets `datetime` as wall-clock time in `tz` (see `localize` for
+    /// daylight-saving gaps and overlaps).
+    pub fn from_local(datetime: ParsiDateTime, tz: Tz) -> Result<ParsiZonedDateTime> {
+        let naive = datetime
+            .to_gregorian()
+            .map_err(|e| map_mitra_error(e, "converting to Gregorian"))?;
+        Ok(ParsiZonedDateTime {
+            datetime,
+            local: localize(tz, naive)?,
+        })
+    }
+
+    /// The instant `secs` seconds after the Unix epoch (1970-01-01 00:00:00 UTC), as
+    /// Parsi wall-clock time in `tz`.
+    pub fn from_unix_timestamp(secs: i64, tz: Tz) -> Result<ParsiZonedDateTime> {
+        match DateTime::from_timestamp(secs, 0) {
+            Some(utc) => ParsiZonedDateTime::from_utc(utc, tz),
+            None => bail!("Error: Unix timestamp {} is out of range.", secs),
+        }
+    }
+
+    /// Seconds since the Unix epoch (1970-01-01 00:00:00 UTC); negative before it.
+    pub fn to_unix_timestamp(&self) -> i64 {
+        self.local.timestamp()
+    }
+
+    /// The same instant in another zone.
+    pub fn with_timezone(&self, tz: Tz) -> Result<ParsiZonedDateTime> {
+        ParsiZonedDateTime::from_utc(self.to_utc(), tz)
+    }
+
+    /// The Parsi wall-clock datetime.
+    pub fn datetime(&self) -> ParsiDateTime {
+        self.datetime
+    }
+
+    /// The UTC offset in effect at this instant.
+    pub fn offset(&self) -> FixedOffset {
+        self.local.offset().fix()
+    }
+
+    /// The instant in UTC.
+    pub fn to_utc(&self) -> DateTime<Utc> {
+        self.local.with_timezone(&Utc)
+    }
+
+    /// The Gregorian wall-clock time in this zone.
+    pub fn to_gregorian(&self) -> DateTime<Tz> {
+        self.local
+    }
+}
+
+impl fmt::Display for ParsiZonedDateTime {
+    /// Formats as e.g. `1403/12/26 09:30:00 +03:30 Asia/Tehran`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} {} {}",
+            self.datetime,
+            self.local.format("%:z"),
+            self.local.timezone().name()
+        )
+    }
+}
@@ -10,66 +10,472 @@
 //! Contains utility functions used by command handlers, such as parsing input strings,
 //! printing results consistently, and mapping errors.
 
-use anyhow::{Result, anyhow, bail};
-use parsidate::{DateError, ParseErrorKind, ParsiDate, ParsiDateTime};
+use crate::clock;
+use crate::natural;
+use crate::rfc3339;
+use anyhow::{Context, Result, anyhow, bail};
+use parsidate::{
+    DateError, MAX_PARSI_DATE, MIN_PARSI_DATE, ParseErrorKind, ParsiDate, ParsiDateTime,
+};
+use std::ops::RangeInclusive;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+/// Whether date inputs must be written exactly in a canonical numeric form (set by `--strict`).
+static STRICT_PARSING: AtomicBool = AtomicBool::new(false);
+
+/// Enables or disables strict parsing for all subsequent date inputs.
+pub fn set_strict_parsing(strict: bool) {
+    STRICT_PARSING.store(strict, Ordering::Relaxed);
+}
+
+/// Returns `true` if strict parsing is enabled.
+pub fn is_strict_parsing() -> bool {
+    STRICT_PARSING.load(Ordering::Relaxed)
+}
+
+/// How digits are written in human-readable output.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DigitStyle {
+    /// ASCII digits (0-9), the default.
+    Ascii,
+    /// Persian digits (۰-۹), selected with `--persian-digits`.
+    Persian,
+}
+
+impl DigitStyle {
+    /// Rewrites the ASCII digits in `text` in this style. ANSI escape sequences (such as
+    /// the highlight `cal` puts on today) are left intact.
+    pub fn apply(self, text: &str) -> String {
+        if self == DigitStyle::Ascii {
+            return text.to_string();
+        }
+        let mut output = String::with_capacity(text.len() * 2);
+        let mut rest = text;
+        // Convert the text between escape sequences; each sequence runs from ESC through
+        // its first ASCII letter and is copied as is.
+        while let Some(start) = rest.find('\u{1b}') {
+            output.push_str(&to_persian_digits(&rest[..start]));
+            let sequence = &rest[start..];
+            let end = sequence[1..]
+                .find(|c: char| c.is_ascii_alphabetic())
+                .map_or(sequence.len(), |i| i + 2);
+            output.push_str(&sequence[..end]);
+            rest = &sequence[end..];
+        }
+        output.push_str(&to_persian_digits(rest));
+        output
+    }
+}
+
+/// Whether human-readable output uses Persian digits (set by `--persian-digits`).
+static PERSIAN_DIGITS: AtomicBool = AtomicBool::new(false);
+
+/// Sets the digit style for all subsequent human-readable output.
+pub fn set_digit_style(style: DigitStyle) {
+    PERSIAN_DIGITS.store(style == DigitStyle::Persian, Ordering::Relaxed);
+}
+
+/// Returns the digit style selected for human-readable output.
+pub fn digit_style() -> DigitStyle {
+    if PERSIAN_DIGITS.load(Ordering::Relaxed) {
+        DigitStyle::Persian
+    } else {
+        DigitStyle::Ascii
+    }
+}
+
+/// Like `println!`, but writes digits in the style selected with `--persian-digits`.
+/// Used by the human-readable output of `now`, `cal`, `info`, `format`, and `print_result`;
+/// JSON and other machine-readable output keeps ASCII digits.
+macro_rules! styled_println {
+    ($($arg:tt)*) => {
+        println!("{}", $crate::utils::digit_style().apply(&format!($($arg)*)))
+    };
+}
+pub(crate) use styled_println;
+
+/// Checks that `input` has the exact shape `YYYY/MM/DD` or `YYYY-MM-DD`, optionally followed by
+/// ` HH:MM:SS` (or `THH:MM:SS` after a dashed date), with ASCII digits only. Returns an error
+/// naming the first offending position (1-based, in characters). Range checks are left to the
+/// actual parser.
+pub fn check_strict_shape(input: &str) -> Result<()> {
+    let chars: Vec<char> = input.chars().collect();
+    let mut pos = 0;
+
+    let fail = |pos: usize, expected: &str| -> anyhow::Error {
+        let found = match chars.get(pos) {
+            Some(c) => format!("'{}'", c),
+            None => "end of input".to_string(),
+        };
+        anyhow!(
+            "Error: Strict parsing of '{}' failed at position {}: expected {}, found {}.",
+            input,
+            pos + 1,
+            expected,
+            found
+        )
+    };
+    let digits = |pos: &mut usize, count: usize, what: &str| -> Result<()> {
+        let start = *pos;
+        while chars.get(*pos).is_some_and(|c| c.is_ascii_digit()) {
+            *pos += 1;
+        }
+        let found = *pos - start;
+        if found == 2 && count == 4 {
+            bail!(
+                "Error: Strict parsing of '{}' failed at position {}: ambiguous two-digit year '{}'; write the full 4-digit year.",
+                input,
+                start + 1,
+                chars[start..*pos].iter().collect::<String>()
+            );
+        }
+        if found != count {
+            // Point at the first character that breaks the expected digit count.
+            let at = if found > count { start + count } else { *pos };
+            return Err(fail(at, &format!("a {}-digit {}", count, what)));
+        }
+        Ok(())
+    };
+    let expect = |pos: &mut usize, allowed: &[char], what: &str| -> Result<char> {
+        match chars.get(*pos) {
+            Some(c) if allowed.contains(c) => {
+                *pos += 1;
+                Ok(*c)
+            }
+            _ => Err(fail(*pos, what)),
+        }
+    };
+
+    digits(&mut pos, 4, "year")?;
+    let separator = expect(&mut pos, &['/', '-'], "'/' or '-'")?;
+    digits(&mut pos, 2, "month")?;
+    expect(&mut pos, &[separator], &format!("'{}'", separator))?;
+    digits(&mut pos, 2, "day")?;
+    if pos == chars.len() {
+        return Ok(());
+    }
+
+    // The 'T' separator is only used with ISO-style dashed dates.
+    if separator == '-' {
+        expect(
+            &mut pos,
+            &[' ', 'T'],
+            "end of input, ' ', or 'T' before a time",
+        )?;
+    } else {
+        expect(&mut pos, &[' '], "end of input or ' ' before a time")?;
+    }
+    digits(&mut pos, 2, "hour")?;
+    expect(&mut pos, &[':'], "':'")?;
+    digits(&mut pos, 2, "minute")?;
+    expect(&mut pos, &[':'], "':'")?;
+    digits(&mut pos, 2, "second")?;
+    if pos != chars.len() {
+        return Err(fail(pos, "end of input"));
+    }
+    Ok(())
+}
+
+// Common formats accepted by `parse_input_datetime_or_date`, tried in order (DateTime first).
+const DATETIME_FORMATS: [&str; 3] = [
+    "%Y/%m/%d %H:%M:%S", // Slash date, space time
+    "%Y-%m-%dT%T",       // ISO date, T separator, T time macro (%H:%M:%S)
+    "%Y-%m-%d %H:%M:%S", // ISO date, space time
+];
+const DATE_FORMATS: [&str; 2] = [
+    "%Y/%m/%d", // Slash date
+    "%Y-%m-%d", // ISO date
+];
 
 /// Attempts to parse the input string first as a ParsiDateTime, then as a ParsiDate,
-/// trying common formats (slash-separated and ISO-like).
+/// trying common formats (slash-separated and ISO-like), and finally as a Persian
+/// natural-language phrase relative to today (see the `natural` module).
 /// Returns the parsed ParsiDateTime and a boolean indicating if the input included time.
 /// This is used by commands that accept flexible date/datetime input.
+///
+/// An RFC 3339 timestamp (Gregorian, with a UTC offset) is accepted too, as the Parsi
+/// wall-clock time at its own offset.
+///
+/// In strict mode (see `set_strict_parsing`), the input is not trimmed, must match the canonical
+/// numeric shape checked by `check_strict_shape` or be an RFC 3339 timestamp, and
+/// natural-language phrases are not accepted.
 pub fn parse_input_datetime_or_date(input: &str) -> Result<(ParsiDateTime, bool)> {
-    // Trim whitespace from input for robustness.
+    if let Ok((pdt, _)) = rfc3339::parse_rfc3339(input) {
+        log::debug!("Parsed '{}' as an RFC 3339 timestamp", input);
+        return Ok((pdt, true));
+    }
+    let strict = is_strict_parsing();
+    if strict {
+        check_strict_shape(input)?;
+    }
+    // Trim whitespace from input for robustness (a no-op in strict mode, which rejects it).
     let trimmed_input = input.trim();
 
-    // Define common formats to try, prioritizing more specific ones (DateTime) first.
-    let dt_formats = [
-        "%Y/%m/%d %H:%M:%S", // Slash date, space time
-        "%Y-%m-%dT%T",       // ISO date, T separator, T time macro (%H:%M:%S)
-        "%Y-%m-%d %H:%M:%S", // ISO date, space time
-                             // Add more formats if needed, e.g., with different separators or orders
-                             // "%Y.%m.%d %H:%M:%S",
-    ];
-    let d_formats = [
-        "%Y/%m/%d", // Slash date
-        "%Y-%m-%d", // ISO date
-                    // "%Y.%m.%d",
-    ];
-
     // 1. Try parsing as DateTime using various common formats.
-    for fmt in dt_formats {
-        if let Ok(pdt) = ParsiDateTime::parse(trimmed_input, fmt) {
-            return Ok((pdt, true)); // Success as DateTime
+    for fmt in DATETIME_FORMATS {
+        match ParsiDateTime::parse(trimmed_input, fmt) {
+            Ok(pdt) => {
+                log::debug!("Parsed '{}' as datetime with '{}'", trimmed_input, fmt);
+                return Ok((pdt, true)); // Success as DateTime
+            }
+            Err(e) => log::trace!("Datetime format '{}' did not match: {:?}", fmt, e),
         }
     }
 
     // 2. Try parsing as Date using various common formats.
-    for fmt in d_formats {
-        if let Ok(pd) = ParsiDate::parse(trimmed_input, fmt) {
-            // Convert ParsiDate to ParsiDateTime at 00:00:00.
-            // Use new_unchecked as ParsiDate is valid and time 00:00:00 is always valid.
-            let pdt =
-                unsafe { ParsiDateTime::new_unchecked(pd.year(), pd.month(), pd.day(), 0, 0, 0) };
-            return Ok((pdt, false)); // Success as Date
+    for fmt in DATE_FORMATS {
+        match ParsiDate::parse(trimmed_input, fmt) {
+            Ok(pd) => {
+                log::debug!("Parsed '{}' as date with '{}'", trimmed_input, fmt);
+                // Convert ParsiDate to ParsiDateTime at 00:00:00.
+                // Use new_unchecked as ParsiDate is valid and time 00:00:00 is always valid.
+                let pdt = unsafe {
+                    ParsiDateTime::new_unchecked(pd.year(), pd.month(), pd.day(), 0, 0, 0)
+                };
+                return Ok((pdt, false)); // Success as Date
+            }
+            Err(e) => log::trace!("Date format '{}' did not match: {:?}", fmt, e),
         }
     }
 
-    // 3. If none of the common formats worked, return an error.
+    // 3. Try interpreting the input as a relative date (e.g., "فردا", "next saturday", "+3d").
+    //    Phrases are never accepted in strict mode. The shape check already passed, so a
+    //    failure here means a component is out of range.
+    if strict {
+        bail!(
+            "Error: '{}' is well-formed but not a valid Parsi date/time (a component is out of range, e.g., month 13, day 31 in Mehr, or hour 24).{}",
+            trimmed_input,
+            suggestion_suffix(trimmed_input)
+        );
+    }
+    let today = clock::today()?;
+    if let Some(pd) = natural::parse_relative(trimmed_input, &today) {
+        log::debug!("Parsed '{}' as relative date: {}", trimmed_input, pd);
+        let pdt = unsafe { ParsiDateTime::new_unchecked(pd.year(), pd.month(), pd.day(), 0, 0, 0) };
+        return Ok((pdt, false));
+    }
+
+    // 4. If none of the common formats worked, return an error.
     bail!(
-        "Could not parse input '{}'. Expected common formats like YYYY/MM/DD, YYYY-MM-DD, YYYY/MM/DD HH:MM:SS, or YYYY-MM-DDTHH:MM:SS.",
-        trimmed_input
+        "Could not parse input '{}'. Expected common formats like YYYY/MM/DD, YYYY-MM-DD, YYYY/MM/DD HH:MM:SS, YYYY-MM-DDTHH:MM:SS, or a relative date such as \"فردا\", \"سه‌شنبهٔ آینده\", \"next saturday\", or \"+3d\".{}",
+        trimmed_input,
+        suggestion_suffix(trimmed_input)
     )
 }
 
+/// Returns `true` if `input` parses as a Parsi date or datetime in one of the common formats.
+fn parses_as_parsi(input: &str) -> bool {
+    DATETIME_FORMATS
+        .iter()
+        .any(|fmt| ParsiDateTime::parse(input, fmt).is_ok())
+        || DATE_FORMATS
+            .iter()
+            .any(|fmt| ParsiDate::parse(input, fmt).is_ok())
+}
+
+/// Formats the result of `suggest_correction` for appending to a parse error message.
+fn suggestion_suffix(input: &str) -> String {
+    suggest_correction(input)
+        .map(|suggestion| format!(" Did you mean {}?", suggestion))
+        .unwrap_or_default()
+}
+
+/// Looks for the date the user most likely meant when `input` failed to parse, and returns it
+/// as a backticked command or date. Checked in order: Persian/Arabic-Indic digits or unusual
+/// separators ('.', '\', '_') around an otherwise valid date, a Gregorian date, day and month
+/// swapped, and day-first order (DD/MM/YYYY).
+fn suggest_correction(input: &str) -> Option<String> {
+    // Normalize digits and separators, then split off an optional time part.
+    let normalized: String = to_ascii_digits(input.trim())
+        .chars()
+        .map(|c| match c {
+            '.' | '\\' | '_' => '/',
+            c => c,
+        })
+        .collect();
+    let (date_part, time_part) = match normalized.split_once([' ', 'T']) {
+        Some((date, time)) => (date, Some(time.trim())),
+        None => (normalized.as_str(), None),
+    };
+    let parts: Vec<&str> = date_part.split(['/', '-']).collect();
+    let [a, b, c] = parts.as_slice() else {
+        return None;
+    };
+    if [a, b, c]
+        .iter()
+        .any(|p| p.is_empty() || !p.chars().all(|ch| ch.is_ascii_digit()))
+    {
+        return None;
+    }
+    let (na, nb, nc): (u32, u32, u32) = (a.parse().ok()?, b.parse().ok()?, c.parse().ok()?);
+    let with_time = |date: String| match time_part {
+        Some(time) => format!("{} {}", date, time),
+        None => date,
+    };
+    let parsi =
+        |year: i32, month: u32, day: u32| with_time(format!("{:04}/{:02}/{:02}", year, month, day));
+    let gregorian = |year: i32, month: u32, day: u32| {
+        chrono::NaiveDate::from_ymd_opt(year, month, day)
+            .filter(|_| (1800..=2200).contains(&year))
+            .map(|_| {
+                let date = with_time(format!("{:04}-{:02}-{:02}", year, month, day));
+                // Quote datetimes so the suggested command can be pasted as-is.
+                if time_part.is_some() {
+                    format!("`mitra from-gregorian \"{}\"`", date)
+                } else {
+                    format!("`mitra from-gregorian {}`", date)
+                }
+            })
+    };
+
+    // Year first: the input may only need normalizing, be Gregorian, or have day and month swapped.
+    if a.len() == 4 {
+        let year = i32::try_from(na).ok()?;
+        let canonical = parsi(year, nb, nc);
+        if canonical != input.trim() && parses_as_parsi(&canonical) {
+            return Some(format!("`{}`", canonical));
+        }
+        if let Some(command) = gregorian(year, nb, nc) {
+            return Some(command);
+        }
+        let swapped = parsi(year, nc, nb);
+        if parses_as_parsi(&swapped) {
+            return Some(format!("`{}` (day and month swapped)", swapped));
+        }
+    }
+
+    // Day first (DD/MM/YYYY): reverse the components.
+    if c.len() == 4 {
+        let year = i32::try_from(nc).ok()?;
+        let reversed = parsi(year, nb, na);
+        if parses_as_parsi(&reversed) {
+            return Some(format!("`{}` (dates are written year first)", reversed));
+        }
+        if let Some(command) = gregorian(year, nb, na) {
+            return Some(command);
+        }
+    }
+    None
+}
+
+/// Parses a Gregorian date or datetime in common ISO or slash formats (DateTime first), or an
+/// RFC 3339 timestamp (taken as wall-clock time at its offset).
+/// Returns the parsed NaiveDateTime (midnight for plain dates) and whether the input included time.
+/// In strict mode, the input must also pass `check_strict_shape` (chrono alone accepts
+/// unpadded components and short years) unless it is an RFC 3339 timestamp.
+pub fn parse_gregorian_input(input: &str) -> Result<(chrono::NaiveDateTime, bool)> {
+    if let Ok((pdt, _)) = rfc3339::parse_rfc3339(input) {
+        let gregorian_ndt = pdt
+            .to_gregorian()
+            .map_err(|e| map_mitra_error(e, "converting to Gregorian"))?;
+        return Ok((gregorian_ndt, true));
+    }
+    if is_strict_parsing() {
+        check_strict_shape(input)?;
+    }
+    let trimmed_input = input.trim();
+    let mut was_datetime = false; // Track if the input included time
+
+    let gregorian_ndt = chrono::NaiveDateTime::parse_from_str(trimmed_input, "%Y-%m-%d %H:%M:%S")
+        .or_else(|_| chrono::NaiveDateTime::parse_from_str(trimmed_input, "%Y-%m-%dT%H:%M:%S"))
+        .or_else(|_| chrono::NaiveDateTime::parse_from_str(trimmed_input, "%Y/%m/%d %H:%M:%S"))
+        .inspect(|_ndt| {
+            was_datetime = true; // Successfully parsed as DateTime
+        })
+        .or_else(|_| {
+            // If DateTime parsing fails, try parsing as NaiveDate.
+            chrono::NaiveDate::parse_from_str(trimmed_input, "%Y-%m-%d")
+                .or_else(|_| chrono::NaiveDate::parse_from_str(trimmed_input, "%Y/%m/%d"))
+                .map(|nd| {
+                    was_datetime = false; // Successfully parsed as Date
+                    // Convert NaiveDate to NaiveDateTime at midnight.
+                    nd.and_hms_opt(0, 0, 0).unwrap() // 00:00:00 is always valid
+                })
+        })
+        // If both fail, return an error.
+        .with_context(|| format!("Could not parse Gregorian date/datetime '{}'. Use formats like YYYY-MM-DD, YYYY-MM-DD HH:MM:SS, or YYYY-MM-DDTHH:MM:SS", trimmed_input))?;
+
+    Ok((gregorian_ndt, was_datetime))
+}
+
 /// Prints the resulting ParsiDateTime, showing only the date part if the original input was just a date.
-/// Uses the default `Display` implementation for each type.
+/// Uses the default `Display` implementation for each type, in the selected digit style.
 pub fn print_result(pdt: ParsiDateTime, was_datetime: bool) {
     if was_datetime {
-        println!("{}", pdt); // Print full DateTime (e.g., "1403/05/02 10:30:00")
+        styled_println!("{}", pdt); // Print full DateTime (e.g., "1403/05/02 10:30:00")
     } else {
-        println!("{}", pdt.date()); // Print only the Date part (e.g., "1403/05/02")
+        styled_println!("{}", pdt.date()); // Print only the Date part (e.g., "1403/05/02")
+    }
+}
+
+/// Replaces ASCII digits (0-9) in a string with their Persian equivalents (۰-۹).
+/// All other characters are left untouched.
+pub fn to_persian_digits(input: &str) -> String {
+    input
+        .chars()
+        .map(|c| match c.to_digit(10) {
+            Some(d) => char::from_u32('۰' as u32 + d).unwrap_or(c),
+            None => c,
+        })
+        .collect()
+}
+
+/// Replaces Persian (۰-۹) and Arabic-Indic (٠-٩) digits in a string with ASCII digits.
+/// All other characters are left untouched.
+pub fn to_ascii_digits(input: &str) -> String {
+    input
+        .chars()
+        .map(|c| match c {
+            '۰'..='۹' => char::from_u32(c as u32 - '۰' as u32 + '0' as u32).unwrap_or(c),
+            '٠'..='٩' => char::from_u32(c as u32 - '٠' as u32 + '0' as u32).unwrap_or(c),
+            c => c,
+        })
+        .collect()
+}
+
+/// Returns the signed number of days from `from` to `to` (negative if `to` is earlier).
+pub fn signed_days_between(from: &ParsiDate, to: &ParsiDate) -> Result<i64> {
+    let from_g = from
+        .to_gregorian()
+        .map_err(|e| map_mitra_error(e, "converting date to Gregorian"))?;
+    let to_g = to
+        .to_gregorian()
+        .map_err(|e| map_mitra_error(e, "converting date to Gregorian"))?;
+    Ok((to_g - from_g).num_days())
+}
+
+/// Describes a signed day span in Persian, e.g. "۴۵ روز مانده" (future),
+/// "۱۲ روز گذشته" (past), or "امروز" (zero).
+pub fn humanize_day_span(days: i64) -> String {
+    match days {
+        0 => "امروز".to_string(),
+        d if d > 0 => format!("{} روز مانده", to_persian_digits(&d.to_string())),
+        d => format!("{} روز گذشته", to_persian_digits(&d.abs().to_string())),
     }
 }
 
+/// Returns the range of Parsi years supported by every command (1..=9999).
+/// The bounds come from `parsidate`, whose 33-year leap cycle and Gregorian conversion
+/// are only defined for this range, so it cannot be extended here.
+pub fn supported_range() -> RangeInclusive<i32> {
+    MIN_PARSI_DATE.year()..=MAX_PARSI_DATE.year()
+}
+
+/// Fails with a clear message if `year` is outside `supported_range()`.
+/// Entry points that take a bare year call this before doing any date work.
+pub fn check_supported_year(year: i32) -> Result<()> {
+    let range = supported_range();
+    if !range.contains(&year) {
+        bail!(
+            "Error: Year {} is outside the supported range {}..={}.",
+            year,
+            range.start(),
+            range.end()
+        );
+    }
+    Ok(())
+}
+
 /// Maps internal `mitra::DateError` types to more user-friendly `anyhow::Error`
 /// messages suitable for CLI output, providing context about the operation being performed.
 pub fn map_mitra_error(err: DateError, context_msg: &str) -> anyhow::Error {
@@ -77,20 +483,48 @@ pub fn map_mitra_error(err: DateError, context_msg: &str) -> anyhow::Error {
         DateError::ParseError(kind) => {
             // Provide specific messages for different parsing failures.
             let kind_msg = match kind {
-                ParseErrorKind::FormatMismatch => "input string does not match expected format or has extra characters",
-                ParseErrorKind::InvalidNumber => "could not parse number, required digits mismatch, or value out of range",
-                ParseErrorKind::InvalidDateValue => "parsed values form a logically invalid date (e.g., day 31 in Mehr, Esfand 30 in non-leap year)",
-                ParseErrorKind::InvalidTimeValue => "parsed values form a logically invalid time (e.g., hour 24, minute 60)",
-                ParseErrorKind::UnsupportedSpecifier => "format pattern contains specifier unsupported for parsing (e.g., %A, %j)",
-                ParseErrorKind::InvalidMonthName => "could not recognize Persian month name in input",
-                ParseErrorKind::InvalidWeekdayName => "could not recognize Persian weekday name in input", // Currently unused for parsing
+                ParseErrorKind::FormatMismatch => {
+                    "input string does not match expected format or has extra characters"
+                }
+                ParseErrorKind::InvalidNumber => {
+                    "could not parse number, required digits mismatch, or value out of range"
+                }
+                ParseErrorKind::InvalidDateValue => {
+                    "parsed values form a logically invalid date (e.g., day 31 in Mehr, Esfand 30 in non-leap year)"
+                }
+                ParseErrorKind::InvalidTimeValue => {
+                    "parsed values form a logically invalid time (e.g., hour 24, minute 60)"
+                }
+                ParseErrorKind::UnsupportedSpecifier => {
+                    "format pattern contains specifier unsupported for parsing (e.g., %A, %j)"
+                }
+                ParseErrorKind::InvalidMonthName => {
+                    "could not recognize Persian month name in input"
+                }
+                ParseErrorKind::InvalidWeekdayName => {
+                    "could not recognize Persian weekday name in input"
+                } // Currently unused for parsing
             };
             format!("Parse error: {}", kind_msg)
         }
         DateError::InvalidDate => "Operation resulted in an invalid date".to_string(),
         DateError::InvalidTime => "Operation resulted in an invalid time".to_string(),
-        DateError::GregorianConversionError => "Gregorian conversion failed. Input might be outside supported range (e.g., before 622 AD)".to_string(),
-        DateError::ArithmeticOverflow => "Date arithmetic resulted in overflow/underflow or went outside supported year range [1, 9999]".to_string(),
+        DateError::GregorianConversionError => {
+            let range = supported_range();
+            format!(
+                "Gregorian conversion failed. The date is likely outside the supported Parsi year range {}..={} (e.g., before 622 AD)",
+                range.start(),
+                range.end()
+            )
+        }
+        DateError::ArithmeticOverflow => {
+            let range = supported_range();
+            format!(
+                "Date arithmetic resulted in overflow/underflow or went outside the supported Parsi year range {}..={}",
+                range.start(),
+                range.end()
+            )
+        }
         DateError::InvalidOrdinal => "Invalid ordinal day number used".to_string(),
     };
     // Combine the specific error message with the context of the operation.
@@ -0,0 +1,119 @@
+//  ~/src/weekday.rs
+//
+//  * Copyright (C) 2024–2025 Parsicore <parsicore.dev@gmail.com>
+//  * Package : mitra
+//  * License : Apache-2.0
+//  * Version : 2.3.0
+//  * URL     : https://github.com/parsicore/Mitra
+//  * Sign: mitra-20250419-bd5fbe728fa2-5836b45f25d83501625cc5529193d5f0
+//
+//! Locale-independent weekday numbering. `ParsiDate::weekday()` returns a Persian
+//! name whose spelling is up to `parsidate`; layout and date arithmetic use the
+//! `Weekday` enum instead, which is derived from the Gregorian equivalent.
+//!
+//! `WeekExt` adds week boundaries and week-of-year numbers to `ParsiDate` for a
+//! configurable first day of the week.
+
+use crate::utils::map_mitra_error;
+use anyhow::Result;
+use chrono::Datelike;
+use clap::ValueEnum;
+use parsidate::ParsiDate;
+
+/// A day of the week, in Iranian order (the week starts on Saturday).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Weekday {
+    Saturday,
+    Sunday,
+    Monday,
+    Tuesday,
+    Wednesday,
+    Thursday,
+    Friday,
+}
+
+impl Weekday {
+    /// Returns the weekday of a Parsi date.
+    pub fn of(date: &ParsiDate) -> Result<Weekday> {
+        let gregorian = date
+            .to_gregorian()
+            .map_err(|e| map_mitra_error(e, "getting weekday"))?;
+        Ok(match gregorian.weekday() {
+            chrono::Weekday::Sat => Weekday::Saturday,
+            chrono::Weekday::Sun => Weekday::Sunday,
+            chrono::Weekday::Mon => Weekday::Monday,
+            chrono::Weekday::Tue => Weekday::Tuesday,
+            chrono::Weekday::Wed => Weekday::Wednesday,
+            chrono::Weekday::Thu => Weekday::Thursday,
+            chrono::Weekday::Fri => Weekday::Friday,
+        })
+    }
+
+    /// Saturday-based number of this weekday (0=Sat, 6=Fri).
+    pub fn number(self) -> u32 {
+        self as u32
+    }
+}
+
+/// Returns the Saturday-based weekday number (0=Sat, 6=Fri) of a date.
+pub fn weekday_number(date: &ParsiDate) -> Result<u32> {
+    Weekday::of(date).map(Weekday::number)
+}
+
+/// The first day of the week, as chosen with `cal --first-day`.
+#[derive(ValueEnum, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum WeekStart {
+    Sat, // Iranian default
+    Sun, // Common in diaspora calendars
+    Mon, // Common in Afghanistan and ISO-style calendars
+}
+
+impl WeekStart {
+    /// Returns the Saturday-based weekday number (0=Sat, 6=Fri) of this first day.
+    pub fn offset(self) -> u32 {
+        match self {
+            WeekStart::Sat => 0,
+            WeekStart::Sun => 1,
+            WeekStart::Mon => 2,
+        }
+    }
+}
+
+/// Week arithmetic on `ParsiDate`, for weeks starting on `first_day`.
+pub trait WeekExt {
+    /// The first day of the week containing this date.
+    fn start_of_week(&self, first_day: WeekStart) -> Result<ParsiDate>;
+
+    /// The last day of the week containing this date.
+    fn end_of_week(&self, first_day: WeekStart) -> Result<ParsiDate>;
+
+    /// The week of the year (1-54): week 1 is the week containing 1 Farvardin, and
+    /// weeks are numbered consecutively from there. For Saturday-first weeks this
+    /// matches `parsidate`'s `ParsiDate::week_of_year()`, which is fixed to Saturday.
+    fn week_of_year_from(&self, first_day: WeekStart) -> Result<u32>;
+}
+
+impl WeekExt for ParsiDate {
+    fn start_of_week(&self, first_day: WeekStart) -> Result<ParsiDate> {
+        let days_into_week = (weekday_number(self)? + 7 - first_day.offset()) % 7;
+        self.add_days(-i64::from(days_into_week))
+            .map_err(|e| map_mitra_error(e, "finding start of week"))
+    }
+
+    fn end_of_week(&self, first_day: WeekStart) -> Result<ParsiDate> {
+        self.start_of_week(first_day)?
+            .add_days(6)
+            .map_err(|e| map_mitra_error(e, "finding end of week"))
+    }
+
+    fn week_of_year_from(&self, first_day: WeekStart) -> Result<u32> {
+        // Count from the weekday offset of 1 Farvardin rather than the start of its week,
+        // which lies in the previous year (and before year 1 for 1/01/01).
+        let new_year = self.first_day_of_year();
+        let lead = (weekday_number(&new_year)? + 7 - first_day.offset()) % 7;
+        let ordinal = self
+            .ordinal()
+            .map_err(|e| map_mitra_error(e, "getting day of year"))?;
+        Ok((ordinal - 1 + lead) / 7 + 1)
+    }
+}
@@ -0,0 +1,59 @@
+//  ~/src/worldclock.rs
+//
+//  * Copyright (C) 2024–2025 Parsicore <parsicore.dev@gmail.com>
+//  * Package : mitra
+//  * License : Apache-2.0
+//  * Version : 2.3.0
+//  * URL     : https://github.com/parsicore/Mitra
+//  * Sign: mitra-20250419-bd5fbe728fa2-5836b45f25d83501625cc5529193d5f0
+//
+//! Rows for the `worldclock` command: one instant shown as Parsi and Gregorian
+//! wall-clock time in several time zones.
+
+use crate::timezone;
+use crate::utils::map_mitra_error;
+use anyhow::Result;
+use chrono::{DateTime, Utc};
+use chrono_tz::Tz;
+use parsidate::ParsiDateTime;
+use serde::Serialize;
+
+/// The instant as seen in one zone.
+#[derive(Serialize, Debug)]
+pub struct ZoneTime {
+    pub city: String,
+    pub zone: String,
+    pub parsi: String,
+    pub weekday: String,
+    pub gregorian: String,
+    /// UTC offset, e.g. "+03:30".
+    pub offset: String,
+    /// Calendar days ahead of (positive) or behind (negative) the first zone's date.
+    pub day_offset: i64,
+}
+
+/// Shows `instant` in each of `zones`, in order. Day offsets are relative to the first zone.
+pub fn at(instant: DateTime<Utc>, zones: &[Tz]) -> Result<Vec<ZoneTime>> {
+    let mut rows = Vec::with_capacity(zones.len());
+    let mut reference_date = None;
+    for &tz in zones {
+        let local = instant.with_timezone(&tz);
+        let naive = local.naive_local();
+        let parsi = ParsiDateTime::from_gregorian(naive)
+            .map_err(|e| map_mitra_error(e, "converting to Parsi"))?;
+        let reference = *reference_date.get_or_insert(naive.date());
+        rows.push(ZoneTime {
+            city: timezone::city_label(tz),
+            zone: tz.name().to_string(),
+            parsi: parsi.to_string(),
+            weekday: parsi
+                .date()
+                .weekday()
+                .map_err(|e| map_mitra_error(e, "getting weekday"))?,
+            gregorian: naive.format("%Y-%m-%d %H:%M:%S").to_string(),
+            offset: local.format("%:z").to_string(),
+            day_offset: (naive.date() - reference).num_days(),
+        });
+    }
+    Ok(rows)
+}
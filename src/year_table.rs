@@ -0,0 +1,83 @@
+//  ~/src/year_table.rs
+//
+//  * Copyright (C) 2024–2025 Parsicore <parsicore.dev@gmail.com>
+//  * Package : mitra
+//  * License : Apache-2.0
+//  * Version : 2.3.0
+//  * URL     : https://github.com/parsicore/Mitra
+//  * Sign: mitra-20250419-bd5fbe728fa2-5836b45f25d83501625cc5529193d5f0
+//
+//! Table-driven Parsi/Gregorian date conversion, built with the `lookup-tables` feature
+//! and used by `batch` for high-volume input.
+//!
+//! `parsidate` converts by summing the lengths of every year since year 1, so each call
+//! costs time proportional to the year. This module precomputes the Julian Day Number of
+//! 1 Farvardin for every supported year once (from the same leap rule), after which a
+//! conversion is a table lookup plus day-of-year arithmetic. Results are identical.
+//!
+//! Only `parsidate` and `chrono` are used, so `benches/conversion.rs` can include this
+//! file directly and compare it with `parsidate`.
+
+use chrono::{Datelike, NaiveDate};
+use once_cell::sync::Lazy;
+use parsidate::{MAX_PARSI_DATE, ParsiDate};
+
+/// Julian Day Number of 1 Farvardin 1 (Gregorian 622-03-21).
+const JDN_PARSI_EPOCH: i64 = 1_948_320;
+
+/// Julian Day Number of 0001-01-01 (proleptic Gregorian), day 1 of chrono's CE count.
+const JDN_CE_DAY_ONE: i64 = 1_721_426;
+
+/// Days in Farvardin through Shahrivar, the six 31-day months.
+const FIRST_HALF_DAYS: u32 = 186;
+
+/// Julian Day Number of 1 Farvardin of year `index + 1`, for every supported year plus
+/// one sentinel entry for the day after the last supported year.
+static YEAR_STARTS: Lazy<Vec<i64>> = Lazy::new(|| {
+    let mut starts = Vec::with_capacity(MAX_PARSI_DATE.year() as usize + 1);
+    let mut jdn = JDN_PARSI_EPOCH;
+    for year in 1..=MAX_PARSI_DATE.year() {
+        starts.push(jdn);
+        jdn += if ParsiDate::is_persian_leap_year(year) {
+            366
+        } else {
+            365
+        };
+    }
+    starts.push(jdn);
+    starts
+});
+
+/// The Gregorian date of a (valid) Parsi date.
+pub fn to_gregorian(date: &ParsiDate) -> NaiveDate {
+    let day_of_year = if date.month() <= 6 {
+        (date.month() - 1) * 31 + date.day()
+    } else {
+        FIRST_HALF_DAYS + (date.month() - 7) * 30 + date.day()
+    };
+    let jdn = YEAR_STARTS[date.year() as usize - 1] + i64::from(day_of_year) - 1;
+    NaiveDate::from_num_days_from_ce_opt((jdn - JDN_CE_DAY_ONE + 1) as i32)
+        .expect("supported Parsi dates are within chrono's range")
+}
+
+/// The Parsi date of a Gregorian date, or `None` outside the supported range.
+pub fn from_gregorian(date: NaiveDate) -> Option<ParsiDate> {
+    let jdn = i64::from(date.num_days_from_ce()) - 1 + JDN_CE_DAY_ONE;
+    let starts = &*YEAR_STARTS;
+    if jdn < starts[0] || jdn >= starts[starts.len() - 1] {
+        return None;
+    }
+    // Nowruz falls in March, so the Parsi year is 622 behind before it and 621 after.
+    let mut index = (date.year() - 623).clamp(0, starts.len() as i32 - 2) as usize;
+    if starts[index + 1] <= jdn {
+        index += 1;
+    }
+    let day_of_year = (jdn - starts[index]) as u32;
+    let (month, day) = if day_of_year < FIRST_HALF_DAYS {
+        (day_of_year / 31 + 1, day_of_year % 31 + 1)
+    } else {
+        let rest = day_of_year - FIRST_HALF_DAYS;
+        (rest / 30 + 7, rest % 30 + 1)
+    };
+    ParsiDate::new(index as i32 + 1, month, day).ok()
+}
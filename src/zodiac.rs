@@ -0,0 +1,72 @@
+//  ~/src/zodiac.rs
+//
+//  * Copyright (C) 2024–2025 Parsicore <parsicore.dev@gmail.com>
+//  * Package : mitra
+//  * License : Apache-2.0
+//  * Version : 2.3.0
+//  * URL     : https://github.com/parsicore/Mitra
+//  * Sign: mitra-20250419-bd5fbe728fa2-5836b45f25d83501625cc5529193d5f0
+//
+//! Traditional zodiac lookups printed on Iranian calendars: the 12-animal year cycle
+//! (e.g., "سال مار") and the solar zodiac signs (e.g., "برج حمل"), which coincide
+//! exactly with the Persian months.
+
+/// The 12 animals of the traditional year cycle, in order, as (Persian, English) names.
+const ANIMALS: [(&str, &str); 12] = [
+    ("موش", "Mouse"),
+    ("گاو", "Cow"),
+    ("پلنگ", "Leopard"),
+    ("خرگوش", "Rabbit"),
+    ("نهنگ", "Whale"),
+    ("مار", "Snake"),
+    ("اسب", "Horse"),
+    ("گوسفند", "Sheep"),
+    ("میمون", "Monkey"),
+    ("مرغ", "Rooster"),
+    ("سگ", "Dog"),
+    ("خوک", "Pig"),
+];
+
+/// A known anchor for the cycle: 1404 is the year of the Snake (index 5 in `ANIMALS`).
+const ANCHOR_YEAR: i32 = 1404;
+const ANCHOR_INDEX: i32 = 5;
+
+/// Returns the (Persian, English) animal names for the given Parsi year.
+/// The cycle repeats every 12 years and works for any year, including years before the anchor.
+pub fn animal_of_year(year: i32) -> (&'static str, &'static str) {
+    let index = (year - ANCHOR_YEAR + ANCHOR_INDEX).rem_euclid(12) as usize;
+    ANIMALS[index]
+}
+
+/// Returns the traditional Persian label for the given Parsi year (e.g., "سال مار").
+pub fn animal_year_name(year: i32) -> String {
+    format!("سال {}", animal_of_year(year).0)
+}
+
+/// The 12 solar zodiac signs, one per Persian month starting with فروردین, as
+/// (Persian, English) names.
+const SIGNS: [(&str, &str); 12] = [
+    ("حمل", "Aries"),
+    ("ثور", "Taurus"),
+    ("جوزا", "Gemini"),
+    ("سرطان", "Cancer"),
+    ("اسد", "Leo"),
+    ("سنبله", "Virgo"),
+    ("میزان", "Libra"),
+    ("عقرب", "Scorpio"),
+    ("قوس", "Sagittarius"),
+    ("جدی", "Capricorn"),
+    ("دلو", "Aquarius"),
+    ("حوت", "Pisces"),
+];
+
+/// Returns the (Persian, English) solar zodiac sign names for a Parsi month (1-12).
+/// The sign starts on the first day of the month and ends on its last day.
+pub fn sign_of_month(month: u32) -> (&'static str, &'static str) {
+    SIGNS[(month - 1) as usize]
+}
+
+/// Returns the traditional Persian label for a Parsi month's sign (e.g., "برج حمل").
+pub fn sign_name(month: u32) -> String {
+    format!("برج {}", sign_of_month(month).0)
+}
@@ -0,0 +1,707 @@
+//  ~/tests/cli.rs
+//
+//  * Copyright (C) 2024–2025 Parsicore <parsicore.dev@gmail.com>
+//  * Package : mitra
+//  * License : Apache-2.0
+//  * Version : 2.3.0
+//  * URL     : https://github.com/parsicore/Mitra
+//  * Sign: mitra-20250419-bd5fbe728fa2-5836b45f25d83501625cc5529193d5f0
+//
+//! End-to-end tests: every subcommand is run as a real process with the clock pinned by
+//! `MITRA_NOW`, and its output is compared against a snapshot in `tests/snapshots/`.
+//!
+//! After an intentional output change, review and accept the new snapshots with
+//! `cargo insta review` (or rerun with `INSTA_UPDATE=always` and inspect the diff).
+//! The snapshots assume the default features (`embedded-events`, `export`).
+#![cfg(all(feature = "embedded-events", feature = "export"))]
+
+use assert_cmd::Command;
+use insta::assert_snapshot;
+
+/// 1403/12/26 09:30 Tehran time: a Sunday near Nowruz, so upcoming holidays are close.
+const FIXED_NOW: &str = "2025-03-16T09:30:00+03:30";
+
+/// A `mitra` command with a pinned clock and no environment-dependent diagnostics.
+fn mitra() -> Command {
+    let mut cmd = assert_cmd::cargo::cargo_bin_cmd!("mitra");
+    cmd.env("MITRA_NOW", FIXED_NOW)
+        .env_remove("MITRA_LOG")
+        .env_remove("MITRA_STAMP_PATTERN")
+        .env_remove("RUST_BACKTRACE")
+        .env_remove("RUST_LIB_BACKTRACE");
+    cmd
+}
+
+/// Runs `mitra` with `args`, asserts success, and returns stdout.
+fn run(args: &[&str]) -> String {
+    let output = mitra().args(args).assert().success().get_output().clone();
+    String::from_utf8(output.stdout).expect("stdout is UTF-8")
+}
+
+/// Runs `mitra` with `args` and `stdin`, asserts success, and returns stdout.
+fn run_with_stdin(args: &[&str], stdin: &str) -> String {
+    let output = mitra()
+        .args(args)
+        .write_stdin(stdin)
+        .assert()
+        .success()
+        .get_output()
+        .clone();
+    String::from_utf8(output.stdout).expect("stdout is UTF-8")
+}
+
+/// Runs `mitra` with `args`, asserts failure, and returns stderr.
+fn run_failing(args: &[&str]) -> String {
+    let output = mitra().args(args).assert().failure().get_output().clone();
+    String::from_utf8(output.stderr).expect("stderr is UTF-8")
+}
+
+// --- Dates and times ---
+
+#[test]
+fn now() {
+    assert_snapshot!("now", run(&["now"]));
+    assert_snapshot!("now_default", run(&[]));
+    assert_snapshot!("now_tz", run(&["now", "--tz", "new_york"]));
+}
+
+#[test]
+fn stamp() {
+    assert_snapshot!("stamp_default", run(&["stamp"]));
+    assert_snapshot!(
+        "stamp_unsafe_pattern",
+        run(&["stamp", "-p", "backup %Y/%m/%d %A %H:%M:%S"])
+    );
+    let from_env = mitra()
+        .args(["stamp"])
+        .env("MITRA_STAMP_PATTERN", "log-%Y-%m-%d")
+        .assert()
+        .success()
+        .get_output()
+        .stdout
+        .clone();
+    assert_eq!(String::from_utf8(from_env).unwrap(), "log-1403-12-26\n");
+}
+
+#[test]
+fn timestamp() {
+    assert_snapshot!("timestamp_now", run(&["timestamp"]));
+    assert_snapshot!("timestamp_to_parsi", run(&["timestamp", "1742103000"]));
+    assert_snapshot!(
+        "timestamp_to_parsi_tz",
+        run(&["timestamp", "-86400", "--tz", "utc"])
+    );
+    assert_snapshot!(
+        "timestamp_from_parsi",
+        run(&["timestamp", "1403/12/26 09:00:00"])
+    );
+}
+
+#[test]
+fn add_and_sub() {
+    assert_snapshot!("add_days", run(&["add", "1403/12/26", "--days", "10"]));
+    assert_snapshot!("add_months", run(&["add", "1403/06/31", "--months", "1"]));
+    assert_snapshot!(
+        "add_hours",
+        run(&["add", "1403/12/26 22:00:00", "--hours", "3"])
+    );
+    assert_snapshot!("sub_years", run(&["sub", "1403/12/30", "--years", "1"]));
+}
+
+#[test]
+fn business_days() {
+    // 1404/01/01-04 are Nowruz holidays and 1404/01/11-13 include Eid al-Fitr.
+    assert_snapshot!(
+        "add_business_days",
+        run(&["add", "1403/12/26 10:00:00", "--business-days", "5"])
+    );
+    assert_snapshot!(
+        "sub_business_days",
+        run(&["sub", "1404/01/20", "--business-days", "5"])
+    );
+    assert_snapshot!(
+        "diff_business_days",
+        run(&["diff", "1404/01/20", "1404/01/01", "--business-days"])
+    );
+}
+
+#[test]
+fn range() {
+    assert_snapshot!(
+        "range_month_ends",
+        run(&["range", "1403/01/31", "1403/12/30", "--every", "1m"])
+    );
+    assert_snapshot!(
+        "range_reverse_weeks",
+        run(&["range", "1404/01/01", "1404/02/01", "-e", "1w", "--reverse"])
+    );
+    assert_snapshot!("range_count", run(&["range", "1403/12/28", "--count", "4"]));
+}
+
+#[test]
+fn recur() {
+    // 1403 and 1408 are the leap years with an Esfand 30 in this span.
+    assert_snapshot!(
+        "recur_yearly",
+        run(&[
+            "recur",
+            "FREQ=YEARLY;BYMONTH=12;BYMONTHDAY=30",
+            "1400/01/01",
+            "1410/01/01"
+        ])
+    );
+    assert_snapshot!(
+        "recur_every_other_week",
+        run(&[
+            "recur",
+            "FREQ=WEEKLY;INTERVAL=2;BYDAY=WE",
+            "1404/01/01",
+            "-n",
+            "4"
+        ])
+    );
+    assert_snapshot!(
+        "recur_last_friday",
+        run(&[
+            "recur",
+            "FREQ=MONTHLY;BYDAY=-1FR",
+            "1404/01/01",
+            "1404/06/31"
+        ])
+    );
+    assert_snapshot!(
+        "recur_count_until",
+        run(&[
+            "recur",
+            "rrule:freq=monthly;byday=2we,4we;until=1404/02/31;count=3",
+            "1404/01/01"
+        ])
+    );
+    assert_snapshot!(
+        "recur_skips_missing_days",
+        run(&["recur", "FREQ=MONTHLY", "1404/01/31", "1404/12/29"])
+    );
+    // The last Saturday-to-Wednesday day of each month; 1404/05/31 is a Friday.
+    assert_snapshot!(
+        "recur_set_position",
+        run(&[
+            "recur",
+            "FREQ=MONTHLY;BYDAY=SA,SU,MO,TU,WE;BYSETPOS=-1",
+            "1404/04/01",
+            "-n",
+            "3"
+        ])
+    );
+}
+
+#[test]
+fn ics() {
+    let exported = run(&[
+        "recur",
+        "FREQ=MONTHLY;BYDAY=-1FR",
+        "1404/01/01",
+        "1404/06/31",
+        "--ics",
+        "--summary",
+        "Review, monthly",
+    ]);
+    assert!(
+        exported
+            .split_terminator('\n')
+            .all(|line| line.ends_with('\r'))
+    );
+    assert_snapshot!("ics_export", exported.replace("\r\n", "\n"));
+    // The exported rule ends at its last date, so importing lists the same dates.
+    assert_snapshot!(
+        "ics_round_trip",
+        run_with_stdin(&["ics", "-", "--from", "1404/01/01"], &exported)
+    );
+
+    let calendar = "BEGIN:VCALENDAR\n\
+        BEGIN:VEVENT\n\
+        SUMMARY:Team sync\n\
+        DTSTART;TZID=Asia/Tehran:20250409T100000\n\
+        RRULE:FREQ=WEEKLY;BYDAY=WE;UNTIL=20250520T000000Z;WKST=MO\n\
+        EXDATE;TZID=Asia/Tehran:20250423T100000\n\
+        RDATE;VALUE=DATE:20250426\n\
+        END:VEVENT\n\
+        BEGIN:VEVENT\n\
+        SUMMARY:Standup\n\
+        DTSTART;VALUE=DATE:20250405\n\
+        RRULE:FREQ=DAILY;INTERVAL=3;COUNT=4\n\
+        END:VEVENT\n\
+        END:VCALENDAR\n";
+    assert_snapshot!(
+        "ics_import",
+        run_with_stdin(&["ics", "-", "--from", "1404/01/01"], calendar)
+    );
+
+    let monthly = "BEGIN:VEVENT\nSUMMARY:Rent\nDTSTART:20250401\nRRULE:FREQ=MONTHLY\nEND:VEVENT\n";
+    let output = mitra()
+        .args(["ics", "-"])
+        .write_stdin(monthly)
+        .assert()
+        .failure()
+        .get_output()
+        .clone();
+    assert_snapshot!(
+        "ics_rejects_gregorian_months",
+        String::from_utf8(output.stderr).expect("stderr is UTF-8")
+    );
+}
+
+#[test]
+fn format() {
+    assert_snapshot!(
+        "format_long",
+        run(&["format", "1403/12/26", "--style", "long"])
+    );
+    assert_snapshot!(
+        "format_pattern",
+        run(&["format", "1403/12/26 09:30:00", "-p", "%A %d %B %Y ساعت %T"])
+    );
+}
+
+#[test]
+fn diff_until_since() {
+    assert_snapshot!("diff", run(&["diff", "1403/01/01", "1404/01/01"]));
+    assert_snapshot!(
+        "diff_breakdown",
+        run(&["diff", "1404/01/01 08:00:00", "1402/06/31 09:30:15"])
+    );
+    assert_snapshot!("until", run(&["until", "1404/01/01"]));
+    assert_snapshot!("since", run(&["since", "1403/12/01"]));
+    assert_snapshot!("until_past", run_failing(&["until", "1403/12/01"]));
+    assert_snapshot!("since_future", run_failing(&["since", "1404/01/01"]));
+}
+
+#[test]
+fn relative_dates() {
+    assert_snapshot!(
+        "relative_persian",
+        run(&["weekday", "امروز", "فردا", "پس‌فردا", "دیروز"])
+    );
+    assert_snapshot!(
+        "relative_english",
+        run(&["weekday", "next saturday", "Last Friday", "next month"])
+    );
+    assert_snapshot!(
+        "relative_offsets",
+        run(&["weekday", "--", "+3d", "-2w", "+1m", "-1y"])
+    );
+}
+
+#[test]
+fn weekday() {
+    assert_snapshot!("weekday_single", run(&["weekday", "1403/12/26"]));
+    assert_snapshot!(
+        "weekday_range",
+        run(&["weekday", "--from", "1403/12/26", "--to", "1404/01/02"])
+    );
+}
+
+#[test]
+fn time_zones() {
+    assert_snapshot!(
+        "to_gregorian_tz",
+        run(&[
+            "to-gregorian",
+            "1403/12/26 09:30:00",
+            "--tz",
+            "tehran",
+            "--to-tz",
+            "utc"
+        ])
+    );
+    assert_snapshot!(
+        "from_gregorian_tz",
+        run(&[
+            "from-gregorian",
+            "2025-03-16 06:00:00",
+            "--tz",
+            "UTC",
+            "--to-tz",
+            "Asia/Tehran"
+        ])
+    );
+}
+
+#[test]
+fn rfc3339() {
+    assert_snapshot!(
+        "rfc3339_input",
+        run(&["add", "2025-03-16T09:30:00.250+03:30", "--days", "1"])
+    );
+    assert_snapshot!(
+        "rfc3339_strict",
+        run(&["--strict", "weekday", "2025-03-16T06:00:00Z"])
+    );
+    assert_snapshot!(
+        "rfc3339_from_gregorian_tz",
+        run(&["from-gregorian", "2025-03-16T06:00:00Z", "--tz", "tehran"])
+    );
+    assert_snapshot!(
+        "rfc3339_output",
+        run(&[
+            "to-gregorian",
+            "1403/12/26 09:30:00",
+            "--tz",
+            "tehran",
+            "--to-tz",
+            "utc",
+            "--rfc3339"
+        ])
+    );
+    assert_snapshot!(
+        "rfc3339_timestamp",
+        run(&["timestamp", "2025-03-16T09:30:00+03:30"])
+    );
+}
+
+#[test]
+fn conversions() {
+    assert_snapshot!("to_gregorian", run(&["to-gregorian", "1403/12/26"]));
+    assert_snapshot!(
+        "from_gregorian",
+        run(&["from-gregorian", "2025-03-16 09:30:00"])
+    );
+    assert_snapshot!("from_julian", run(&["from-julian", "1582-10-04"]));
+    assert_snapshot!(
+        "from_julian_leap_day",
+        run(&["from-julian", "1700-02-29 12:00:00"])
+    );
+    assert_snapshot!("to_julian", run(&["to-julian", "1403/12/26"]));
+    assert_snapshot!("from_hijri", run(&["from-hijri", "1446/10/01"]));
+    assert_snapshot!(
+        "from_hijri_gregorian",
+        run(&["from-hijri", "1445/12/30 18:00:00", "--gregorian"])
+    );
+    assert_snapshot!("to_hijri", run(&["to-hijri", "1403/12/26 09:30:00"]));
+    assert_snapshot!("to_hijri_gregorian", run(&["to-hijri", "2025-03-01", "-g"]));
+    assert_snapshot!("is_leap", run(&["is-leap", "1403"]));
+    assert_snapshot!("parse", run(&["parse", "26 اسفند 1403", "-p", "%d %B %Y"]));
+}
+
+#[test]
+fn anniversary() {
+    assert_snapshot!(
+        "anniversary_esfand_30",
+        run(&["anniversary", "1399/12/30", "-n", "6"])
+    );
+    assert_snapshot!(
+        "anniversary_gregorian_leap_day",
+        run(&["anniversary", "2024-02-29", "--gregorian", "-n", "5"])
+    );
+}
+
+#[test]
+fn worldclock() {
+    assert_snapshot!(
+        "worldclock_now",
+        run(&["worldclock", "--cities", "tehran,berlin,toronto"])
+    );
+    assert_snapshot!(
+        "worldclock_given_json",
+        run(&[
+            "worldclock",
+            "1404/01/02 00:00:00",
+            "-c",
+            "Asia/Tehran,los angeles",
+            "--json"
+        ])
+    );
+}
+
+#[test]
+fn is_workday() {
+    assert_snapshot!("is_workday_today", run(&["is-workday"]));
+    assert_snapshot!("is_workday_holiday", run(&["is-workday", "1404/01/01"]));
+    // Eid al-Fitr 1445, outside the reference year of the bundled Hijri mapping.
+    assert_snapshot!(
+        "is_workday_lunar_holiday",
+        run(&["is-workday", "1403/01/22"])
+    );
+    assert_snapshot!(
+        "is_workday_raw",
+        run(&["--raw", "is-workday", "1404/01/08"])
+    );
+
+    // Exit statuses are only distinct with --fail-if-holiday.
+    mitra()
+        .args(["is-workday", "1404/01/06", "--fail-if-holiday"])
+        .assert()
+        .code(0);
+    mitra()
+        .args(["is-workday", "1404/01/08", "--fail-if-holiday"])
+        .assert()
+        .code(2);
+    mitra()
+        .args(["is-workday", "1404/01/01", "--fail-if-holiday"])
+        .assert()
+        .code(3);
+    mitra()
+        .args(["is-workday", "1403/01/22", "--fail-if-holiday"])
+        .assert()
+        .code(3);
+}
+
+#[test]
+fn info() {
+    assert_snapshot!("info_text", run(&["info", "1403/12/26 09:30:00"]));
+    assert_snapshot!("info_json", run(&["info", "1404/01/01", "--json"]));
+    assert_snapshot!("info_phrase", run(&["info", "جمعه آینده", "--raw"]));
+    assert_snapshot!(
+        "info_field",
+        run(&["info", "1404/01/13", "--field", "last-day-of-quarter"])
+    );
+}
+
+// --- Calendars ---
+
+#[test]
+fn persian_digits() {
+    assert_snapshot!(
+        "persian_digits_format",
+        run(&[
+            "--persian-digits",
+            "format",
+            "1403/12/26 09:30:00",
+            "-p",
+            "%d %B %Y ساعت %T"
+        ])
+    );
+    assert_snapshot!(
+        "persian_digits_info",
+        run(&["info", "1403/12/30", "--persian-digits"])
+    );
+    assert_snapshot!(
+        "persian_digits_cal",
+        run(&["cal", "12", "1403", "--persian-digits"])
+    );
+}
+
+#[test]
+fn cal() {
+    assert_snapshot!("cal_current", run(&["cal"]));
+    assert_snapshot!("cal_month_events", run(&["cal", "1", "1404", "--events"]));
+    assert_snapshot!("cal_three_months", run(&["cal", "-3"]));
+    assert_snapshot!(
+        "cal_year_monday",
+        run(&["cal", "-y", "1404", "--first-day", "mon"])
+    );
+}
+
+#[test]
+fn map_zodiac_and_sign() {
+    assert_snapshot!("map", run(&["map", "-y", "1404"]));
+    assert_snapshot!("zodiac", run(&["zodiac", "1404"]));
+    assert_snapshot!("sign_today", run(&["sign"]));
+    assert_snapshot!("sign_date", run(&["sign", "1404/07/30"]));
+}
+
+#[test]
+fn nowruz() {
+    // FIXED_NOW is four days before Sāl Tahvil 1404.
+    assert_snapshot!("nowruz_next", run(&["nowruz"]));
+    assert_snapshot!("nowruz_past", run(&["nowruz", "1403"]));
+}
+
+// --- Events ---
+
+#[test]
+fn events() {
+    assert_snapshot!("events", run(&["events", "1404/01/01", "1404/01/13"]));
+    assert_snapshot!("onthisday", run(&["onthisday", "1403/12/29"]));
+    let today = mitra()
+        .args(["today", "--occasions", "--category", "history"])
+        .env("MITRA_NOW", "2025-03-19T10:00:00+03:30")
+        .assert()
+        .success()
+        .get_output()
+        .stdout
+        .clone();
+    assert_snapshot!(
+        "today_occasions",
+        String::from_utf8(today).expect("stdout is UTF-8")
+    );
+}
+
+#[test]
+fn leave() {
+    assert_snapshot!("leave_nowruz", run(&["leave", "1403/12/26", "1404/01/15"]));
+    assert_snapshot!(
+        "leave_exclude_thursdays_json",
+        run(&[
+            "leave",
+            "1404/02/01",
+            "1404/02/07",
+            "--exclude-thursdays",
+            "--json"
+        ])
+    );
+}
+
+#[test]
+fn academic() {
+    assert_snapshot!("academic_today", run(&["academic"]));
+    assert_snapshot!(
+        "academic_custom_terms_json",
+        run(&[
+            "academic",
+            "1403/07/10",
+            "--term-start",
+            "15 شهریور",
+            "--second-term",
+            "11/15",
+            "--json"
+        ])
+    );
+    assert_snapshot!("academic_summer", run(&["academic", "1404/04/15"]));
+}
+
+#[test]
+fn fiscal_deadlines() {
+    assert_snapshot!("fiscal_deadlines_year", run(&["fiscal-deadlines", "1404"]));
+    assert_snapshot!(
+        "fiscal_deadlines_upcoming_json",
+        run(&["fiscal-deadlines", "--upcoming", "20", "--json"])
+    );
+}
+
+#[test]
+fn export() {
+    assert_snapshot!("export_csv", run(&["export", "-y", "1404", "-f", "csv"]));
+    assert_snapshot!(
+        "export_org_agenda",
+        run(&["export", "-y", "1404", "-f", "org-agenda"])
+    );
+    assert_snapshot!("export_sql", run(&["export", "-y", "1404", "-f", "sql"]));
+
+    // Binary formats are only checked for their container signature.
+    let xlsx = mitra()
+        .args(["export", "-y", "1404", "-f", "xlsx"])
+        .assert()
+        .success()
+        .get_output()
+        .stdout
+        .clone();
+    assert!(
+        xlsx.starts_with(b"PK"),
+        "xlsx output is not a zip container"
+    );
+}
+
+#[test]
+fn feed() {
+    // Ten days from 1403/12/26 cover the 29 Esfand holiday and the first days of Nowruz.
+    assert_snapshot!("feed_atom", run(&["feed", "--days", "10"]));
+    assert_snapshot!("feed_rss", run(&["feed", "-f", "rss", "--days", "10"]));
+}
+
+// --- Integrations ---
+
+#[test]
+fn applet_feed() {
+    assert_snapshot!("applet_feed", run(&["applet-feed", "--pretty"]));
+}
+
+#[test]
+fn statusbar() {
+    assert_snapshot!(
+        "statusbar_text",
+        run(&["statusbar", "-f", "{weekday} {date} | {next_event}"])
+    );
+    assert_snapshot!("statusbar_json", run(&["statusbar", "--json"]));
+}
+
+#[test]
+fn rpc() {
+    let requests = concat!(
+        r#"{"jsonrpc":"2.0","id":1,"method":"to_gregorian","params":{"date":"1403/12/26"}}"#,
+        "\n",
+        r#"{"jsonrpc":"2.0","id":2,"method":"add","params":{"date":"1403/12/26","days":5}}"#,
+        "\n",
+        r#"{"jsonrpc":"2.0","id":3,"method":"nope"}"#,
+        "\n",
+        "not json\n",
+    );
+    assert_snapshot!("rpc", run_with_stdin(&["rpc"], requests));
+}
+
+#[test]
+fn pick() {
+    assert_snapshot!("pick_days", run(&["pick"]));
+    assert_snapshot!("pick_events", run(&["pick", "1", "1404", "--events"]));
+    assert_snapshot!(
+        "pick_select",
+        run(&["pick", "--select", "1404/01/01 جمعه آغاز نوروز"])
+    );
+}
+
+#[test]
+fn batch() {
+    assert_snapshot!(
+        "batch_to_gregorian",
+        run_with_stdin(&["batch", "to-gregorian"], "1403/12/26\n\n1404/01/01\n")
+    );
+    assert_snapshot!(
+        "batch_weekday_skip_errors",
+        run_with_stdin(
+            &["batch", "weekday", "--skip-errors"],
+            "1403/12/26\nnot a date\n1404/01/01\n"
+        )
+    );
+}
+
+#[test]
+fn schedule() {
+    assert_snapshot!(
+        "schedule_month_ends",
+        run(&["schedule", "--at", "*-*-last 23:30", "--next", "4"])
+    );
+    assert_snapshot!(
+        "schedule_workdays",
+        run(&[
+            "schedule",
+            "--at",
+            "*-1,7-first-workday 09:00",
+            "--next",
+            "4"
+        ])
+    );
+    assert_snapshot!(
+        "schedule_invalid",
+        run_failing(&["schedule", "--at", "1403-13-01", "--next", "1"])
+    );
+}
+
+#[test]
+fn doctor() {
+    assert_snapshot!("doctor", run(&["doctor"]));
+}
+
+// --- Errors ---
+
+#[test]
+fn errors() {
+    assert_snapshot!(
+        "error_gregorian_suggestion",
+        run_failing(&["to-gregorian", "2024-07-31"])
+    );
+    assert_snapshot!(
+        "error_strict_position",
+        run_failing(&["--strict", "info", "04/01/01"])
+    );
+    assert_snapshot!(
+        "error_year_out_of_range",
+        run_failing(&["is-leap", "10000"])
+    );
+    assert_snapshot!(
+        "error_hijri_invalid_day",
+        run_failing(&["from-hijri", "1446/12/30"])
+    );
+    assert_snapshot!(
+        "error_hijri_strict",
+        run_failing(&["--strict", "from-hijri", "1446/9/1"])
+    );
+}